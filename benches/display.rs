@@ -0,0 +1,25 @@
+use aws_resource_id::{AwsInstanceId, AwsTransitGatewayAttachmentId};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::fmt::Write;
+
+fn display(c: &mut Criterion) {
+    let short: AwsInstanceId = "i-1234abcd".parse().unwrap();
+    let long: AwsTransitGatewayAttachmentId = "tgw-attach-1234567890abcdef0".parse().unwrap();
+    let mut buf = String::with_capacity(64);
+
+    c.bench_function("display short", |b| {
+        b.iter(|| {
+            buf.clear();
+            write!(buf, "{}", black_box(&short)).unwrap();
+        })
+    });
+    c.bench_function("display long", |b| {
+        b.iter(|| {
+            buf.clear();
+            write!(buf, "{}", black_box(&long)).unwrap();
+        })
+    });
+}
+
+criterion_group!(benches, display);
+criterion_main!(benches);
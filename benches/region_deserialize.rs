@@ -0,0 +1,19 @@
+use aws_resource_id::AwsRegionId;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn region_deserialize(c: &mut Criterion) {
+    let json = "\"eu-central-1\"";
+
+    c.bench_function("deserialize region", |b| {
+        b.iter(|| serde_json::from_str::<AwsRegionId>(black_box(json)).unwrap())
+    });
+    c.bench_function("deserialize region via String", |b| {
+        b.iter(|| {
+            let s: String = serde_json::from_str(black_box(json)).unwrap();
+            AwsRegionId::try_from(s).unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, region_deserialize);
+criterion_main!(benches);
@@ -0,0 +1,58 @@
+//! Validates AWS resource and region ids read from stdin, one per line
+//!
+//! ```sh
+//! printf "i-1234abcd\neu-west-1\n" | cargo run --example validate
+//! ```
+use aws_resource_id::{AnyResourceId, AwsRegionId, Error};
+use std::{
+    io::{self, BufRead},
+    process::ExitCode,
+};
+
+/// Detects the type name of an id
+fn identify(id: &str) -> Result<&'static str, Error> {
+    if id.parse::<AwsRegionId>().is_ok() {
+        return Ok("AwsRegionId");
+    }
+    id.parse::<AnyResourceId>().map(|id| id.type_name())
+}
+
+fn main() -> io::Result<ExitCode> {
+    let mut all_valid = true;
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        let id = line.trim();
+        if id.is_empty() {
+            continue;
+        }
+        match identify(id) {
+            Ok(type_name) => println!("{id}: {type_name}"),
+            Err(e) => {
+                all_valid = false;
+                println!("{id}: invalid: {e}");
+            }
+        }
+    }
+    Ok(if all_valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identify() {
+        assert_eq!(identify("eu-west-1").unwrap(), "AwsRegionId");
+        assert_eq!(identify("i-1234abcd").unwrap(), "AwsInstanceId");
+        assert_eq!(
+            identify("tgw-attach-1a2b3c4d5e6f7j8h9").unwrap(),
+            "AwsTransitGatewayAttachmentId"
+        );
+        assert!(identify("i-123").is_err());
+        assert!(identify("invalid").is_err());
+    }
+}
@@ -0,0 +1,169 @@
+//! # AWS Account ID
+//!
+//! A 12-digit account number, e.g. `123456789012`. Leading zeros are
+//! significant, so the id is stored as digits rather than a number.
+use alloc::string::{String, ToString};
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+/// Error encountered when parsing an AWS account id
+#[derive(Debug, thiserror::Error)]
+#[error("invalid account id \"{0}\", expected 12 digits")]
+pub struct AccountIdError(String);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for AccountIdError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serialize_error(
+            serializer,
+            "AccountIdError",
+            "invalid_account_id",
+            Some(self.0.as_str()),
+            self,
+        )
+    }
+}
+
+/// AWS Account ID
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AwsAccountId([u8; 12]);
+
+impl AwsAccountId {
+    /// The account id as a string slice
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.0).unwrap_or_default()
+    }
+}
+
+impl TryFrom<&str> for AwsAccountId {
+    type Error = crate::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let digits: [u8; 12] = s
+            .as_bytes()
+            .try_into()
+            .ok()
+            .filter(|d: &[u8; 12]| d.iter().all(u8::is_ascii_digit))
+            .ok_or_else(|| AccountIdError(s.into()))?;
+        Ok(Self(digits))
+    }
+}
+
+impl TryFrom<String> for AwsAccountId {
+    type Error = crate::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl TryFrom<&String> for AwsAccountId {
+    type Error = crate::Error;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl FromStr for AwsAccountId {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl AsRef<str> for AwsAccountId {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AwsAccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<AwsAccountId> for String {
+    fn from(value: AwsAccountId) -> Self {
+        value.to_string()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for AwsAccountId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AwsAccountId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(AccountIdVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct AccountIdVisitor;
+
+#[cfg(feature = "serde")]
+impl serde::de::Visitor<'_> for AccountIdVisitor {
+    type Value = AwsAccountId;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string AWS account id of 12 digits")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        AwsAccountId::try_from(&*crate::lenient_input(v)).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn test_parse() {
+        let account: AwsAccountId = "012345678901".parse().unwrap();
+        assert_eq!(account.to_string(), "012345678901");
+        assert_eq!(account.as_str(), "012345678901");
+    }
+
+    #[test]
+    fn test_invalid() {
+        for input in ["", "12345678901", "1234567890123", "12345678901a", "١٢٣٤٥٦"] {
+            assert!(
+                matches!(AwsAccountId::try_from(input), Err(Error::Account(_))),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            AwsAccountId::try_from("123").unwrap_err().to_string(),
+            "invalid account id \"123\", expected 12 digits"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let account: AwsAccountId = serde_json::from_str("\"123456789012\"").unwrap();
+        assert_eq!(serde_json::to_string(&account).unwrap(), "\"123456789012\"");
+        assert!(serde_json::from_str::<AwsAccountId>("\"1234\"").is_err());
+    }
+}
@@ -0,0 +1,558 @@
+//! # Amazon Resource Names
+//!
+//! ARNs in the `arn:{partition}:{service}:{region}:{account}:{resource}`
+//! format, e.g. `arn:aws:ec2:us-east-1:123456789012:instance/i-1234abcd`,
+//! checks of their region and account against allowlists, and
+//! `service:resource-type` specs like `ec2:instance`.
+use crate::{AwsAccountId, AwsPartition, AwsRegionId, ResourceId};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+/// Error encountered when parsing a malformed ARN
+#[derive(Debug, thiserror::Error)]
+#[error("invalid ARN \"{0}\", expected \"arn:{{partition}}:{{service}}:{{region}}:{{account}}:{{resource}}\"")]
+pub struct ArnError(String);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ArnError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serialize_error(
+            serializer,
+            "ArnError",
+            "invalid_arn",
+            Some(self.0.as_str()),
+            self,
+        )
+    }
+}
+
+/// Error encountered when parsing a malformed resource spec
+#[derive(Debug, thiserror::Error)]
+#[error("invalid resource spec \"{0}\", expected \"{{service}}:{{resource-type}}\" of lowercase letters, digits and hyphens")]
+pub struct ResourceSpecError(String);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ResourceSpecError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serialize_error(
+            serializer,
+            "ResourceSpecError",
+            "invalid_resource_spec",
+            Some(self.0.as_str()),
+            self,
+        )
+    }
+}
+
+/// Error encountered when an ARN doesn't satisfy an [`ArnConstraint`]
+#[derive(Debug, thiserror::Error)]
+pub enum ArnConstraintError {
+    /// The ARN resource section doesn't reference the id
+    #[error("ARN resource \"{resource}\" doesn't match the id \"{id}\"")]
+    ResourceMismatch {
+        /// The resource section of the ARN
+        resource: String,
+        /// The expected id
+        id: String,
+    },
+    /// The ARN region isn't allowed, `None` for ARNs without a region
+    #[error("region {} isn't allowed", display_or_empty(.0))]
+    RegionNotAllowed(Option<AwsRegionId>),
+    /// The ARN account isn't allowed, `None` for ARNs without an account
+    #[error("account {} isn't allowed", display_or_empty(.0))]
+    AccountNotAllowed(Option<AwsAccountId>),
+}
+
+fn display_or_empty<T: fmt::Display>(value: &Option<T>) -> String {
+    value.as_ref().map_or("(empty)".into(), ToString::to_string)
+}
+
+impl ArnConstraintError {
+    /// Machine-readable error kind, e.g. `region_not_allowed`
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ResourceMismatch { .. } => "resource_mismatch",
+            Self::RegionNotAllowed(_) => "region_not_allowed",
+            Self::AccountNotAllowed(_) => "account_not_allowed",
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ArnConstraintError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let input = match self {
+            Self::ResourceMismatch { resource, .. } => Some(resource.clone()),
+            Self::RegionNotAllowed(region) => region.map(|r| r.to_string()),
+            Self::AccountNotAllowed(account) => account.map(|a| a.to_string()),
+        };
+        crate::serialize_error(
+            serializer,
+            "ArnConstraintError",
+            self.kind(),
+            input.as_deref(),
+            self,
+        )
+    }
+}
+
+/// Amazon Resource Name
+///
+/// The region and account are optional, as they're empty in ARNs of global
+/// services and S3 buckets. ARNs are ordered by partition, service, region,
+/// account and then resource.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Arn {
+    partition: AwsPartition,
+    service: String,
+    region: Option<AwsRegionId>,
+    account: Option<AwsAccountId>,
+    resource: String,
+}
+
+impl Arn {
+    /// The partition
+    #[must_use]
+    pub fn partition(&self) -> AwsPartition {
+        self.partition
+    }
+
+    /// The service namespace, e.g. `ec2`
+    #[must_use]
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// The region, if the ARN has one
+    #[must_use]
+    pub fn region(&self) -> Option<AwsRegionId> {
+        self.region
+    }
+
+    /// The account, if the ARN has one
+    #[must_use]
+    pub fn account(&self) -> Option<AwsAccountId> {
+        self.account
+    }
+
+    /// The resource section, e.g. `instance/i-1234abcd`
+    #[must_use]
+    pub fn resource(&self) -> &str {
+        &self.resource
+    }
+}
+
+impl TryFrom<&str> for Arn {
+    type Error = crate::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let error = || ArnError(s.into());
+        match s.splitn(6, ':').collect::<Vec<_>>()[..] {
+            ["arn", partition, service, region, account, resource]
+                if !service.is_empty() && !resource.is_empty() =>
+            {
+                Ok(Self {
+                    partition: AwsPartition::parse(partition).ok_or_else(error)?,
+                    service: service.into(),
+                    region: Some(region)
+                        .filter(|r| !r.is_empty())
+                        .map(str::parse)
+                        .transpose()?,
+                    account: Some(account)
+                        .filter(|a| !a.is_empty())
+                        .map(str::parse)
+                        .transpose()?,
+                    resource: resource.into(),
+                })
+            }
+            _ => Err(error().into()),
+        }
+    }
+}
+
+impl TryFrom<String> for Arn {
+    type Error = crate::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl TryFrom<&String> for Arn {
+    type Error = crate::Error;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl FromStr for Arn {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for Arn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:{}:{}:{}:{}",
+            self.partition.as_str(),
+            self.service,
+            self.region.as_ref().map_or("", AsRef::as_ref),
+            self.account.as_ref().map_or("", AwsAccountId::as_str),
+            self.resource
+        )
+    }
+}
+
+impl From<Arn> for String {
+    fn from(value: Arn) -> Self {
+        value.to_string()
+    }
+}
+
+/// A resource type of a service, formatted as `{service}:{resource-type}`,
+/// e.g. `ec2:instance`
+///
+/// ```
+/// use aws_resource_id::{AwsInstanceId, ResourceSpec};
+///
+/// let spec: ResourceSpec = "ec2:instance".parse().unwrap();
+/// let id: AwsInstanceId = "i-1234abcd".parse().unwrap();
+/// assert_eq!(spec.arn_resource(&id), "instance/i-1234abcd");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ResourceSpec {
+    service: String,
+    resource_type: String,
+}
+
+impl ResourceSpec {
+    /// The service namespace, e.g. `ec2`
+    #[must_use]
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// The resource type, e.g. `instance`
+    #[must_use]
+    pub fn resource_type(&self) -> &str {
+        &self.resource_type
+    }
+
+    /// The resource section of an ARN for the id, e.g. `instance/i-1234abcd`
+    #[must_use]
+    pub fn arn_resource(&self, id: &impl fmt::Display) -> String {
+        format!("{}/{id}", self.resource_type)
+    }
+
+    /// Checks if the ARN is of the service and resource type
+    #[must_use]
+    pub fn matches(&self, arn: &Arn) -> bool {
+        arn.service() == self.service
+            && arn
+                .resource()
+                .strip_prefix(self.resource_type.as_str())
+                .is_some_and(|rest| rest.starts_with(['/', ':']))
+    }
+}
+
+impl TryFrom<&str> for ResourceSpec {
+    type Error = crate::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let valid_token = |t: &str| {
+            !t.is_empty()
+                && t.bytes()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'-')
+        };
+        match s.split(':').collect::<Vec<_>>()[..] {
+            [service, resource_type] if valid_token(service) && valid_token(resource_type) => {
+                Ok(Self {
+                    service: service.into(),
+                    resource_type: resource_type.into(),
+                })
+            }
+            _ => Err(ResourceSpecError(s.into()).into()),
+        }
+    }
+}
+
+impl TryFrom<String> for ResourceSpec {
+    type Error = crate::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl TryFrom<&String> for ResourceSpec {
+    type Error = crate::Error;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl FromStr for ResourceSpec {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for ResourceSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.service, self.resource_type)
+    }
+}
+
+impl From<ResourceSpec> for String {
+    fn from(value: ResourceSpec) -> Self {
+        value.to_string()
+    }
+}
+
+/// Checks that an ARN references an id and belongs to allowed regions and
+/// accounts, all of them are allowed by default
+///
+/// ```
+/// use aws_resource_id::{Arn, ArnConstraint, AwsInstanceId, AwsRegionId};
+///
+/// let arn: Arn = "arn:aws:ec2:us-east-1:123456789012:instance/i-1234abcd"
+///     .parse()
+///     .unwrap();
+/// let id: AwsInstanceId = "i-1234abcd".parse().unwrap();
+/// let constraint = ArnConstraint::default().allow_region(AwsRegionId::UsEast1);
+/// assert!(constraint.check(&arn, &id).is_ok());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ArnConstraint {
+    regions: Vec<AwsRegionId>,
+    accounts: Vec<AwsAccountId>,
+}
+
+impl ArnConstraint {
+    /// Adds a region to the allowlist, once it's non-empty other regions are
+    /// rejected
+    #[must_use]
+    pub fn allow_region(mut self, region: AwsRegionId) -> Self {
+        self.regions.push(region);
+        self
+    }
+
+    /// Adds an account to the allowlist, once it's non-empty other accounts
+    /// are rejected
+    #[must_use]
+    pub fn allow_account(mut self, account: AwsAccountId) -> Self {
+        self.accounts.push(account);
+        self
+    }
+
+    /// Checks the ARN against the id and the allowlists
+    ///
+    /// The resource section matches if it's the id itself or ends with it
+    /// after a `/` or `:`, e.g. `instance/i-1234abcd`.
+    pub fn check<T: ResourceId>(&self, arn: &Arn, id: &T) -> Result<(), crate::Error> {
+        let id = id.to_string();
+        let resource = arn.resource();
+        let matches = resource == id
+            || resource
+                .strip_suffix(id.as_str())
+                .is_some_and(|rest| rest.ends_with(['/', ':']));
+        if !matches {
+            return Err(ArnConstraintError::ResourceMismatch {
+                resource: resource.into(),
+                id,
+            }
+            .into());
+        }
+        if !self.regions.is_empty() && !arn.region().is_some_and(|r| self.regions.contains(&r)) {
+            return Err(ArnConstraintError::RegionNotAllowed(arn.region()).into());
+        }
+        if !self.accounts.is_empty() && !arn.account().is_some_and(|a| self.accounts.contains(&a)) {
+            return Err(ArnConstraintError::AccountNotAllowed(arn.account()).into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AwsInstanceId, Error};
+
+    const ARN: &str = "arn:aws:ec2:us-east-1:123456789012:instance/i-1234abcd";
+
+    fn instance(s: &str) -> AwsInstanceId {
+        s.parse().unwrap()
+    }
+
+    fn constraint() -> ArnConstraint {
+        ArnConstraint::default()
+            .allow_region(AwsRegionId::UsEast1)
+            .allow_account("123456789012".parse().unwrap())
+    }
+
+    #[test]
+    fn test_parse() {
+        let arn: Arn = ARN.parse().unwrap();
+        assert_eq!(arn.partition(), AwsPartition::Aws);
+        assert_eq!(arn.service(), "ec2");
+        assert_eq!(arn.region(), Some(AwsRegionId::UsEast1));
+        assert_eq!(arn.account().unwrap().as_str(), "123456789012");
+        assert_eq!(arn.resource(), "instance/i-1234abcd");
+        assert_eq!(arn.to_string(), ARN);
+
+        let bucket: Arn = "arn:aws:s3:::my-bucket".parse().unwrap();
+        assert_eq!(bucket.region(), None);
+        assert_eq!(bucket.account(), None);
+        assert_eq!(bucket.to_string(), "arn:aws:s3:::my-bucket");
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut arns: Vec<Arn> = [
+            "arn:aws:sqs:us-east-1:123456789012:queue",
+            "arn:aws-cn:s3:::bucket",
+            "arn:aws:ec2:us-east-1:123456789012:instance/i-abcd1234",
+            "arn:aws:ec2:eu-west-1:123456789012:instance/i-1234abcd",
+            "arn:aws:ec2:us-east-1:123456789012:instance/i-1234abcd",
+        ]
+        .into_iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        arns.sort();
+        let sorted: Vec<String> = arns.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            sorted,
+            [
+                "arn:aws:ec2:eu-west-1:123456789012:instance/i-1234abcd",
+                "arn:aws:ec2:us-east-1:123456789012:instance/i-1234abcd",
+                "arn:aws:ec2:us-east-1:123456789012:instance/i-abcd1234",
+                "arn:aws:sqs:us-east-1:123456789012:queue",
+                "arn:aws-cn:s3:::bucket",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for input in [
+            "",
+            "arn:aws:ec2",
+            "urn:aws:s3:::b",
+            "arn:foo:s3:::b",
+            "arn:aws::::x",
+        ] {
+            assert!(
+                matches!(Arn::try_from(input), Err(Error::Arn(_))),
+                "{input}"
+            );
+        }
+        assert!(matches!(
+            Arn::try_from("arn:aws:ec2:us-east-9:123456789012:instance/i-1234abcd"),
+            Err(Error::Region(_))
+        ));
+        assert!(matches!(
+            Arn::try_from("arn:aws:ec2:us-east-1:1234:instance/i-1234abcd"),
+            Err(Error::Account(_))
+        ));
+    }
+
+    #[test]
+    fn test_resource_spec() {
+        let spec: ResourceSpec = "ec2:instance".parse().unwrap();
+        assert_eq!(spec.service(), "ec2");
+        assert_eq!(spec.resource_type(), "instance");
+        assert_eq!(spec.to_string(), "ec2:instance");
+        assert!(spec.matches(&ARN.parse().unwrap()));
+        assert!(!spec.matches(
+            &"arn:aws:ec2:us-east-1:123456789012:volume/vol-1234abcd"
+                .parse()
+                .unwrap()
+        ));
+        assert!("ec2:security-group".parse::<ResourceSpec>().is_ok());
+    }
+
+    #[test]
+    fn test_resource_spec_invalid() {
+        for input in [
+            "ec2:",
+            ":instance",
+            "ec2",
+            "EC2:instance",
+            "ec2:instance:x",
+            "ec2:in stance",
+        ] {
+            assert!(
+                matches!(ResourceSpec::try_from(input), Err(Error::ResourceSpec(_))),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_constraint_matches() {
+        let arn: Arn = ARN.parse().unwrap();
+        assert!(constraint().check(&arn, &instance("i-1234abcd")).is_ok());
+        assert!(ArnConstraint::default()
+            .check(&arn, &instance("i-1234abcd"))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_constraint_mismatches() {
+        let arn: Arn = ARN.parse().unwrap();
+        assert!(matches!(
+            constraint().check(&arn, &instance("i-abcd1234")),
+            Err(Error::ArnConstraint(
+                ArnConstraintError::ResourceMismatch { .. }
+            ))
+        ));
+
+        let arn: Arn = "arn:aws:ec2:eu-west-1:123456789012:instance/i-1234abcd"
+            .parse()
+            .unwrap();
+        assert!(matches!(
+            constraint().check(&arn, &instance("i-1234abcd")),
+            Err(Error::ArnConstraint(ArnConstraintError::RegionNotAllowed(
+                Some(AwsRegionId::EuWest1)
+            )))
+        ));
+
+        let arn: Arn = "arn:aws:ec2:us-east-1:210987654321:instance/i-1234abcd"
+            .parse()
+            .unwrap();
+        let err = constraint()
+            .check(&arn, &instance("i-1234abcd"))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "account 210987654321 isn't allowed");
+
+        let arn: Arn = "arn:aws:ec2:::instance/i-1234abcd".parse().unwrap();
+        assert!(matches!(
+            constraint().check(&arn, &instance("i-1234abcd")),
+            Err(Error::ArnConstraint(ArnConstraintError::RegionNotAllowed(
+                None
+            )))
+        ));
+    }
+}
@@ -0,0 +1,201 @@
+//! # Serde adapter for ids nested in AWS API responses
+//!
+//! AWS describe responses often wrap ids into single-field objects, e.g.
+//! `{ "InstanceId": "i-1234abcd" }`. Use the module with `#[serde(with)]` to
+//! accept either such an object or a bare id string. Ids are serialized as
+//! bare strings.
+//!
+//! ```
+//! use aws_resource_id::AwsInstanceId;
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Reservation {
+//!     #[serde(with = "aws_resource_id::aws_describe")]
+//!     instance: AwsInstanceId,
+//! }
+//!
+//! let r: Reservation =
+//!     serde_json::from_str(r#"{"instance": {"InstanceId": "i-1234abcd"}}"#).unwrap();
+//! assert_eq!(r.instance.to_string(), "i-1234abcd");
+//! ```
+use alloc::{format, string::String, vec::Vec};
+use core::{fmt, marker::PhantomData, str::FromStr};
+use serde::{
+    de::{self, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Serializes the id as a bare string
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    value.serialize(serializer)
+}
+
+/// Deserializes the id from a bare string or a single-field object
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr<Err = crate::Error>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(DescribeVisitor(PhantomData))
+}
+
+/// Deserializes the id from the value of a tag with the given key in an AWS
+/// tag list, e.g. `[{"Key": "BaseAmi", "Value": "ami-1234abcd"}]`
+///
+/// The key is an extra argument, so wrap it into a function to use with
+/// `#[serde(deserialize_with)]`:
+///
+/// ```
+/// use aws_resource_id::AwsAmiId;
+/// use serde::Deserializer;
+///
+/// fn base_ami<'de, D: Deserializer<'de>>(d: D) -> Result<AwsAmiId, D::Error> {
+///     aws_resource_id::aws_describe::deserialize_tag(d, "BaseAmi")
+/// }
+///
+/// #[derive(serde::Deserialize)]
+/// struct Image {
+///     #[serde(rename = "Tags", deserialize_with = "base_ami")]
+///     base: AwsAmiId,
+/// }
+///
+/// let image: Image =
+///     serde_json::from_str(r#"{"Tags": [{"Key": "BaseAmi", "Value": "ami-1234abcd"}]}"#)
+///         .unwrap();
+/// assert_eq!(image.base.to_string(), "ami-1234abcd");
+/// ```
+pub fn deserialize_tag<'de, T, D>(deserializer: D, key: &str) -> Result<T, D::Error>
+where
+    T: FromStr<Err = crate::Error>,
+    D: Deserializer<'de>,
+{
+    let tags = Vec::<Tag>::deserialize(deserializer)?;
+    let tag = tags
+        .into_iter()
+        .find(|t| t.key == key)
+        .ok_or_else(|| de::Error::custom(format!("no tag with key \"{key}\"")))?;
+    tag.value.parse().map_err(de::Error::custom)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Tag {
+    key: String,
+    value: String,
+}
+
+struct DescribeVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for DescribeVisitor<T>
+where
+    T: FromStr<Err = crate::Error>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an AWS resource id string or a single-field object containing it")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let Some((_, id)) = map.next_entry::<de::IgnoredAny, String>()? else {
+            return Err(de::Error::invalid_length(0, &self));
+        };
+        if map.next_key::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::custom(
+                "expected a single-field object containing an AWS resource id",
+            ));
+        }
+        self.visit_str(&id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AwsInstanceId;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Wrapper {
+        #[serde(with = "crate::aws_describe")]
+        id: AwsInstanceId,
+    }
+
+    fn instance() -> AwsInstanceId {
+        "i-1234abcd".parse().unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_bare() {
+        let w: Wrapper = serde_json::from_str(r#"{"id": "i-1234abcd"}"#).unwrap();
+        assert_eq!(w.id, instance());
+    }
+
+    #[test]
+    fn test_deserialize_wrapped() {
+        let w: Wrapper = serde_json::from_str(r#"{"id": {"InstanceId": "i-1234abcd"}}"#).unwrap();
+        assert_eq!(w.id, instance());
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        assert!(serde_json::from_str::<Wrapper>(r#"{"id": {"InstanceId": "i-1"}}"#).is_err());
+        assert!(serde_json::from_str::<Wrapper>(r#"{"id": {}}"#).is_err());
+        assert!(serde_json::from_str::<Wrapper>(
+            r#"{"id": {"InstanceId": "i-1234abcd", "State": "running"}}"#
+        )
+        .is_err());
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Tagged {
+        #[serde(rename = "Tags", deserialize_with = "base_instance")]
+        base: AwsInstanceId,
+    }
+
+    fn base_instance<'de, D: serde::Deserializer<'de>>(d: D) -> Result<AwsInstanceId, D::Error> {
+        super::deserialize_tag(d, "Base")
+    }
+
+    #[test]
+    fn test_deserialize_tag() {
+        let t: Tagged = serde_json::from_str(
+            r#"{"Tags": [{"Key": "Name", "Value": "web"}, {"Key": "Base", "Value": "i-1234abcd"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(t.base, instance());
+    }
+
+    #[test]
+    fn test_deserialize_tag_missing() {
+        let err = serde_json::from_str::<Tagged>(r#"{"Tags": [{"Key": "Name", "Value": "web"}]}"#)
+            .unwrap_err();
+        assert!(
+            err.to_string().starts_with("no tag with key \"Base\""),
+            "{err}"
+        );
+        assert!(
+            serde_json::from_str::<Tagged>(r#"{"Tags": [{"Key": "Base", "Value": "web"}]}"#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_serialize() {
+        let w = Wrapper { id: instance() };
+        assert_eq!(serde_json::to_string(&w).unwrap(), r#"{"id":"i-1234abcd"}"#);
+    }
+}
@@ -18,12 +18,58 @@
 //! > might have some existing resources with short IDs, however, any new
 //! > resources will receive the longer IDs.
 //! > <https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/resource-ids.html>
+//!
+//! ## Type safety
+//!
+//! Ids of different types don't convert into each other:
+//!
+//! ```compile_fail,E0308
+//! use aws_resource_id::{AwsSnapshotId, AwsVolumeId};
+//!
+//! let snapshot: AwsSnapshotId = "snap-1234abcd".parse().unwrap();
+//! let volume: AwsVolumeId = snapshot;
+//! ```
+//!
+//! ```compile_fail,E0308
+//! use aws_resource_id::{AwsAmiId, AwsInstanceId};
+//!
+//! fn takes_ami(_: AwsAmiId) {}
+//!
+//! let instance: AwsInstanceId = "i-1234abcd".parse().unwrap();
+//! takes_ami(instance);
+//! ```
+//!
+//! ```compile_fail,E0308
+//! use aws_resource_id::{AwsAmiId, AwsInstanceId};
+//!
+//! let instance: AwsInstanceId = "i-1234abcd".parse().unwrap();
+//! let ami = AwsAmiId::from(instance);
+//! ```
+//!
+//! Only the explicit conversions into and from [`AnyResourceId`] are
+//! provided:
+//!
+//! ```
+//! use aws_resource_id::{AnyResourceId, AwsAmiId, AwsInstanceId};
+//!
+//! let instance: AwsInstanceId = "i-1234abcd".parse().unwrap();
+//! let any = AnyResourceId::from(instance);
+//! assert_eq!(AwsInstanceId::try_from(any).unwrap(), instance);
+//! assert!(AwsAmiId::try_from(any).is_err());
+//! ```
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{convert::TryFrom, fmt, ops::Range, str::FromStr};
 #[cfg(feature = "sqlx-postgres")]
 use sqlx::{
     postgres::{PgTypeInfo, PgValueRef},
     Postgres, Type,
 };
-use std::{convert::TryFrom, fmt, str::FromStr};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 /// Error encountered when parsing an AWS resource ID in the general format
 #[derive(Debug, thiserror::Error)]
@@ -39,7 +85,10 @@ pub struct GeneralResourceError {
 
 /// Specific details about errors encountered when parsing AWS resource IDs in
 /// the general format
+///
+/// New variants may be added in minor releases.
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum GeneralResourceErrorDetail {
     /// Incorrect prefix for the resource type
     #[error("incorrect prefix, expected \"{0}\"")]
@@ -50,6 +99,75 @@ pub enum GeneralResourceErrorDetail {
     /// The unique identifier contains invalid characters
     #[error("the unique part contains non ascii alphanumeric characters")]
     NonAsciiAlphanumeric,
+    /// The id starts or ends with whitespace, e.g. copied with a space
+    #[error("the id has leading or trailing whitespace")]
+    SurroundingWhitespace,
+    /// The prefix isn't made of lowercase ascii alphanumeric words each
+    /// followed by a hyphen, e.g. `tgw-rtb-`, or is too long
+    #[error("the prefix must be lowercase ascii alphanumeric words each followed by \"-\", up to {} characters long", MAX_PREFIX_LEN)]
+    InvalidPrefix,
+    /// The prefix doesn't match any known resource type
+    #[error("unknown prefix, expected one of: {}", AnyResourceId::PREFIXES.join(", "))]
+    UnknownPrefix,
+    /// The id pattern is empty, contains invalid characters or a wildcard not
+    /// at the end
+    #[error("the pattern must be ascii alphanumeric characters and hyphens with an optional trailing \"*\"")]
+    InvalidPattern,
+}
+
+impl GeneralResourceErrorDetail {
+    /// Machine-readable error kind, e.g. `wrong_prefix`
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::WrongPrefix(_) => "wrong_prefix",
+            Self::IdLength(_) => "id_length",
+            Self::NonAsciiAlphanumeric => "non_ascii_alphanumeric",
+            Self::InvalidPrefix => "invalid_prefix",
+            Self::UnknownPrefix => "unknown_prefix",
+            Self::InvalidPattern => "invalid_pattern",
+            Self::SurroundingWhitespace => "surrounding_whitespace",
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for GeneralResourceError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serialize_error(
+            serializer,
+            "GeneralResourceError",
+            self.error_detail.kind(),
+            Some(self.input.as_str()),
+            self,
+        )
+    }
+}
+
+/// The maximum length of a formatted general resource id: the longest prefix
+/// (`tgw-connect-peer-`) followed by a 17 characters unique part
+pub const MAX_ID_LEN: usize = 34;
+
+/// The maximum length of a prefix, so that ids with the long unique part fit
+/// into [`MAX_ID_LEN`]
+const MAX_PREFIX_LEN: usize = MAX_ID_LEN - 17;
+
+/// Checks that the prefix consists of lowercase ascii alphanumeric words
+/// each followed by a hyphen, e.g. `tgw-rtb-`, and fits into [`MAX_ID_LEN`]
+fn is_valid_prefix(prefix: &str) -> bool {
+    let Some(words) = prefix.strip_suffix('-') else {
+        return false;
+    };
+    prefix.len() <= MAX_PREFIX_LEN
+        && words.split('-').all(|word| {
+            !word.is_empty()
+                && word
+                    .bytes()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        })
 }
 
 /// The unique alphanumeric part of an AWS resource id in the general format
@@ -60,6 +178,41 @@ enum UniquePart {
 }
 
 impl UniquePart {
+    fn parse(id: &[u8]) -> Result<Self, GeneralResourceErrorDetail> {
+        // The length is checked first so pathological long inputs are rejected
+        // without scanning them
+        if id.len() != 8 && id.len() != 17 {
+            return Err(GeneralResourceErrorDetail::IdLength(id.len()));
+        }
+        if !id.iter().all(u8::is_ascii_alphanumeric) {
+            return Err(GeneralResourceErrorDetail::NonAsciiAlphanumeric);
+        }
+
+        if id.len() == 8 {
+            let mut arr = [0u8; 8];
+            arr.copy_from_slice(id);
+            Ok(Self::C8(arr))
+        } else {
+            let mut arr = [0u8; 17];
+            arr.copy_from_slice(id);
+            Ok(Self::C17(arr))
+        }
+    }
+
+    /// Generates a unique part of `N` lowercase hex digits, like the ones AWS
+    /// issues
+    #[cfg(feature = "arbitrary")]
+    fn arbitrary_chars<const N: usize>(
+        u: &mut arbitrary::Unstructured<'_>,
+    ) -> arbitrary::Result<[u8; N]> {
+        const DIGITS: &[u8] = b"0123456789abcdef";
+        let mut arr = [0u8; N];
+        for c in arr.iter_mut() {
+            *c = *u.choose(DIGITS)?;
+        }
+        Ok(arr)
+    }
+
     fn as_slice(&self) -> &[u8] {
         match self {
             Self::C8(x) => x,
@@ -68,57 +221,284 @@ impl UniquePart {
     }
 }
 
+mod sealed {
+    /// Wraps the unique part, so it doesn't leak into the public interface
+    pub struct Unique(pub(super) super::UniquePart);
+
+    pub trait Sealed {
+        fn from_unique(unique: Unique) -> Self;
+
+        fn unique_bytes(&self) -> &[u8];
+    }
+}
+
+/// A typed AWS resource id in the general format, e.g. [`AwsAmiId`]
+///
+/// The trait is sealed, it's implemented for all the typed ids of the crate.
+pub trait ResourceId: sealed::Sealed + Copy + fmt::Display {
+    /// The id prefix, e.g. `ami-`
+    const PREFIX: &'static str;
+}
+
 macro_rules! impl_resource_id {
-    ($type:ident, $prefix:literal, $doc:literal) => {
+    ($type:ident, $prefix:literal, $doc:literal, $label:literal) => {
         #[doc = $doc]
-        #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        ///
+        /// The id hashes the same way as its string form, though it still
+        /// can't be looked up by `&str` in a hash map as it doesn't implement
+        /// `Borrow<str>`.
+        #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
         pub struct $type(UniquePart);
 
+        const _: () = assert!(
+            $prefix.as_bytes()[$prefix.len() - 1] == b'-',
+            concat!("the prefix of ", stringify!($type), " must end with `-`")
+        );
+        const _: () = assert!(
+            $prefix.len() + 17 <= MAX_ID_LEN,
+            concat!("the ids of ", stringify!($type), " must fit into `MAX_ID_LEN`")
+        );
+
         impl $type {
+            #[doc = concat!("The id prefix: `", $prefix, "`")]
+            pub const PREFIX: &'static str = $prefix;
+
+            #[doc = concat!("Human-readable label of the resource type: `", $label, "`")]
+            ///
+            /// The alternate `{:#}` format prefixes the id with it, e.g.
+            /// `AMI ami-12345678`.
+            pub const LABEL: &'static str = $label;
+
+            /// The length of [`Self::PREFIX`], e.g. for fixed-size buffers
+            pub const PREFIX_LEN: usize = $prefix.len();
+
+            #[doc = concat!("An example id: `", $prefix, "1234567890abcdef0`")]
+            pub const EXAMPLE: Self = Self(UniquePart::C17(*b"1234567890abcdef0"));
+
+            /// The length of the longest formatted id of this type, e.g. for
+            /// column widths
+            #[must_use]
+            pub const fn max_display_len() -> usize {
+                Self::PREFIX_LEN + 17
+            }
+
+            #[doc = concat!("The id prefix: `", $prefix, "`, same as [`Self::PREFIX`]")]
+            #[must_use]
+            pub const fn prefix(&self) -> &'static str {
+                Self::PREFIX
+            }
+
+            /// The unique part of the id without the prefix, e.g. `12345678` for
+            /// `ami-12345678`
+            #[must_use]
+            pub fn id_suffix(&self) -> &str {
+                core::str::from_utf8(self.0.as_slice()).unwrap_or_default()
+            }
+
+            /// Checks if the unique part is 8 characters long, the legacy format
+            #[must_use]
+            pub fn is_short(&self) -> bool {
+                matches!(self.0, UniquePart::C8(_))
+            }
+
+            /// Checks if the unique part is 17 characters long, the current
+            /// format
+            #[must_use]
+            pub fn is_long(&self) -> bool {
+                matches!(self.0, UniquePart::C17(_))
+            }
+
+            /// The length of the formatted id, without formatting it
+            #[must_use]
+            pub fn display_len(&self) -> usize {
+                Self::PREFIX_LEN + self.0.as_slice().len()
+            }
+
+            /// The id as a single-quoted SQL string literal, e.g.
+            /// `'ami-12345678'`
+            ///
+            /// Ids consist of ascii alphanumeric characters and hyphens only, so
+            /// there's nothing to escape. Prefer query parameters anyway, this is
+            /// for the rare cases where SQL has to be built as a string.
+            #[must_use]
+            pub fn to_sql_literal(&self) -> String {
+                format!("'{self}'")
+            }
+
+            /// Partially masks the id for sharing, e.g. `ami-12…78`, keeping
+            /// the first and the last two characters of the unique part
+            #[must_use]
+            pub fn mask(&self) -> String {
+                let unique = core::str::from_utf8(self.0.as_slice()).unwrap_or_default();
+                format!(
+                    "{}{}…{}",
+                    Self::PREFIX,
+                    &unique[..2],
+                    &unique[unique.len() - 2..]
+                )
+            }
+
+            /// Compares ids by their string form, unlike the derived `Ord`,
+            /// which puts short ids before long ones
+            #[must_use]
+            pub fn cmp_by_id(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.as_slice().cmp(other.0.as_slice())
+            }
+
+            /// Parses an id from the start of the string, returning it along with
+            /// the rest of the string, e.g. for log lines
+            ///
+            /// The unique part spans all the alphanumeric characters after the
+            /// prefix, so it fails if they're followed by more of them.
+            pub fn parse_prefix_of(s: &str) -> Result<(Self, &str), $crate::Error> {
+                let end = match s.strip_prefix(Self::PREFIX) {
+                    Some(rest) => {
+                        Self::PREFIX_LEN
+                            + rest.bytes().take_while(u8::is_ascii_alphanumeric).count()
+                    }
+                    None => s.len(),
+                };
+                let (id, rest) = s.split_at(end);
+                Ok((Self::try_from(id)?, rest))
+            }
+
+            /// Reinterprets the unique part of the id as an id of another type,
+            /// e.g. `i-1234abcd` as `vol-1234abcd`
+            ///
+            /// It's a footgun: AWS doesn't relate resources of different types
+            /// by their unique parts, so only use it when a tool knows the ids
+            /// share the unique part, e.g. in synthetic test fixtures.
+            #[must_use]
+            pub fn reinterpret_unique_as<U: ResourceId>(&self) -> U {
+                U::from_unique(sealed::Unique(self.0))
+            }
+
+            /// Compares ids in constant time, e.g. against a secret reference id
+            ///
+            /// Only the length of the unique part (short or long) may leak
+            /// through timing.
+            #[cfg(feature = "subtle")]
+            #[must_use]
+            pub fn ct_eq(&self, other: &Self) -> bool {
+                use subtle::ConstantTimeEq;
+                self.0.as_slice().ct_eq(other.0.as_slice()).into()
+            }
+
+            /// Formats the id into a [`compact_str::CompactString`], which stores
+            /// ids up to 24 bytes inline and longer ones on the heap
+            #[cfg(feature = "compact_str")]
+            #[must_use]
+            pub fn to_compact(&self) -> compact_str::CompactString {
+                let mut s = compact_str::CompactString::const_new(Self::PREFIX);
+                s.push_str(core::str::from_utf8(self.0.as_slice()).unwrap_or_default());
+                s
+            }
+
+            /// Formats the id into a stack-allocated string, e.g. for
+            /// environments without an allocator
+            #[cfg(feature = "heapless")]
+            #[must_use]
+            pub fn to_heapless(&self) -> heapless::String<MAX_ID_LEN> {
+                let mut s = heapless::String::new();
+                // Can't overflow: the length of every id type is checked
+                // against `MAX_ID_LEN` at compile time
+                let unique = core::str::from_utf8(self.0.as_slice()).unwrap_or_default();
+                s.push_str(Self::PREFIX).expect("the prefix fits into `MAX_ID_LEN`");
+                s.push_str(unique).expect("the id fits into `MAX_ID_LEN`");
+                s
+            }
+
+            /// Generates an id with a legacy 8 characters unique part
+            #[cfg(feature = "arbitrary")]
+            pub fn arbitrary_short(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+                UniquePart::arbitrary_chars(u).map(|c| Self(UniquePart::C8(c)))
+            }
+
+            /// Generates an id with a 17 characters unique part
+            #[cfg(feature = "arbitrary")]
+            pub fn arbitrary_long(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+                UniquePart::arbitrary_chars(u).map(|c| Self(UniquePart::C17(c)))
+            }
+        }
+
+        /// Generates either a short or a long id, see
+        #[doc = concat!("[`", stringify!($type), "::arbitrary_short`] and [`", stringify!($type), "::arbitrary_long`]")]
+        /// to force the length
+        #[cfg(feature = "arbitrary")]
+        impl<'a> arbitrary::Arbitrary<'a> for $type {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                if u.arbitrary()? {
+                    Self::arbitrary_long(u)
+                } else {
+                    Self::arbitrary_short(u)
+                }
+            }
+        }
+
+        impl sealed::Sealed for $type {
+            fn from_unique(unique: sealed::Unique) -> Self {
+                Self(unique.0)
+            }
+
+            fn unique_bytes(&self) -> &[u8] {
+                self.0.as_slice()
+            }
+        }
+
+        impl ResourceId for $type {
             const PREFIX: &'static str = $prefix;
         }
 
+        impl $type {
+            fn parse_bytes(bytes: &[u8]) -> Result<Self, GeneralResourceErrorDetail> {
+                let is_space = |c: &u8| c.is_ascii_whitespace();
+                if bytes.first().is_some_and(is_space) || bytes.last().is_some_and(is_space) {
+                    return Err(GeneralResourceErrorDetail::SurroundingWhitespace);
+                }
+                match bytes.strip_prefix(Self::PREFIX.as_bytes()) {
+                    Some(unique) => UniquePart::parse(unique).map($type),
+                    None => Err(GeneralResourceErrorDetail::WrongPrefix(Self::PREFIX)),
+                }
+            }
+        }
+
         impl TryFrom<&str> for $type {
             type Error = $crate::Error;
 
             fn try_from(s: &str) -> Result<Self, Self::Error> {
-                if !s.starts_with(Self::PREFIX) {
-                    return Err(GeneralResourceError::new(
-                        short_type_name::<$type>(),
-                        s,
-                        GeneralResourceErrorDetail::WrongPrefix(Self::PREFIX),
-                    )
-                    .into());
-                }
-                if !s[Self::PREFIX.len()..]
-                    .chars()
-                    .all(|c| c.is_ascii_alphanumeric())
-                {
-                    return Err(GeneralResourceError::new(
-                        short_type_name::<$type>(),
-                        s,
-                        GeneralResourceErrorDetail::NonAsciiAlphanumeric,
-                    )
-                    .into());
-                }
+                Self::parse_bytes(s.as_bytes()).map_err(|detail| {
+                    GeneralResourceError::new(short_type_name::<$type>(), s, detail).into()
+                })
+            }
+        }
 
-                let id = &s[Self::PREFIX.len()..];
-                if id.len() == 8 {
-                    let mut arr = [0u8; 8];
-                    arr.copy_from_slice(id.as_bytes());
-                    Ok($type(UniquePart::C8(arr)))
-                } else if id.len() == 17 {
-                    let mut arr = [0u8; 17];
-                    arr.copy_from_slice(id.as_bytes());
-                    Ok($type(UniquePart::C17(arr)))
-                } else {
-                    Err(GeneralResourceError::new(
+        /// Non UTF-8 input is kept lossily converted in the error
+        impl TryFrom<&[u8]> for $type {
+            type Error = $crate::Error;
+
+            fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+                Self::parse_bytes(bytes).map_err(|detail| {
+                    GeneralResourceError::new(
                         short_type_name::<$type>(),
-                        s,
-                        GeneralResourceErrorDetail::IdLength(id.len()),
+                        String::from_utf8_lossy(bytes),
+                        detail,
                     )
-                    .into())
-                }
+                    .into()
+                })
+            }
+        }
+
+        /// Takes ownership of the buffer, so the error keeps it without copying
+        /// unless it isn't valid UTF-8
+        impl TryFrom<Vec<u8>> for $type {
+            type Error = $crate::Error;
+
+            fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+                Self::parse_bytes(&bytes).map_err(|detail| {
+                    let input = String::from_utf8(bytes)
+                        .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned());
+                    GeneralResourceError::new(short_type_name::<$type>(), input, detail).into()
+                })
             }
         }
 
@@ -148,20 +528,36 @@ macro_rules! impl_resource_id {
 
         impl fmt::Display for $type {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(f, "{}", Self::PREFIX)?;
-                write!(
-                    f,
-                    "{}",
-                    std::str::from_utf8(self.0.as_slice()).unwrap_or_default()
-                )
+                if f.alternate() {
+                    f.write_str(Self::LABEL)?;
+                    f.write_str(" ")?;
+                }
+                f.write_str(Self::PREFIX)?;
+                f.write_str(core::str::from_utf8(self.0.as_slice()).unwrap_or_default())
             }
         }
 
         impl fmt::Debug for $type {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.debug_tuple(short_type_name::<Self>())
-                    .field(&self.to_string())
-                    .finish()
+                let mut t = f.debug_tuple(short_type_name::<Self>());
+                #[cfg(feature = "redact-debug")]
+                t.field(&format_args!("<redacted>"));
+                #[cfg(not(feature = "redact-debug"))]
+                t.field(&self.to_string());
+                t.finish()
+            }
+        }
+
+        impl core::hash::Hash for $type {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                let unique = self.0.as_slice();
+                let len = Self::PREFIX_LEN + unique.len();
+                let mut buf = [0u8; MAX_ID_LEN];
+                buf[..Self::PREFIX_LEN].copy_from_slice(Self::PREFIX.as_bytes());
+                buf[Self::PREFIX_LEN..len].copy_from_slice(unique);
+                core::str::from_utf8(&buf[..len])
+                    .unwrap_or_default()
+                    .hash(state)
             }
         }
 
@@ -192,179 +588,2099 @@ macro_rules! impl_resource_id {
             }
         }
 
-        #[cfg(feature = "sqlx-postgres")]
-        impl<'r> sqlx::decode::Decode<'r, Postgres> for $type {
-            fn decode(
-                value: PgValueRef<'r>,
-            ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
-                let s = <&str as sqlx::decode::Decode<Postgres>>::decode(value)?;
-                Ok($type::try_from(s).map_err(|e| Box::new(sqlx::Error::Decode(e.into())))?)
-            }
+        #[cfg(feature = "sqlx-postgres")]
+        impl<'r> sqlx::decode::Decode<'r, Postgres> for $type {
+            fn decode(
+                value: PgValueRef<'r>,
+            ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+                let s = <&str as sqlx::decode::Decode<Postgres>>::decode(value)?;
+                Ok($type::try_from(s).map_err(|e| Box::new(sqlx::Error::Decode(e.into())))?)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $type {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(&self.to_string())
+            }
+        }
+
+        /// Human-readable formats, like JSON, get the id string, compact
+        /// ones only the unique part bytes as the prefix is implied by the type
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $crate::Compact<$type> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                if serializer.is_human_readable() {
+                    self.0.serialize(serializer)
+                } else {
+                    serializer.serialize_bytes(self.0 .0.as_slice())
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct Visitor;
+
+                impl serde::de::Visitor<'_> for Visitor {
+                    type Value = $type;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str(concat!(
+                            "a string ",
+                            $doc,
+                            " starting with \"",
+                            $prefix,
+                            "\""
+                        ))
+                    }
+
+                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        $type::try_from(&*crate::lenient_input(v)).map_err(E::custom)
+                    }
+                }
+
+                deserializer.deserialize_str(Visitor)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $crate::Compact<$type> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct Visitor;
+
+                impl serde::de::Visitor<'_> for Visitor {
+                    type Value = $type;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str(concat!("the unique part bytes of ", $doc))
+                    }
+
+                    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        UniquePart::parse(v).map($type).map_err(|detail| {
+                            let input = String::from_utf8_lossy(v);
+                            E::custom(GeneralResourceError::new(
+                                short_type_name::<$type>(),
+                                input,
+                                detail,
+                            ))
+                        })
+                    }
+                }
+
+                if deserializer.is_human_readable() {
+                    $type::deserialize(deserializer).map($crate::Compact)
+                } else {
+                    deserializer.deserialize_bytes(Visitor).map($crate::Compact)
+                }
+            }
+        }
+    };
+}
+
+fn short_type_name<T>() -> &'static str {
+    let name = core::any::type_name::<T>();
+    name.split("::").last().unwrap_or(name)
+}
+
+impl GeneralResourceError {
+    fn new(
+        target_type: &'static str,
+        input: impl Into<String>,
+        error_detail: GeneralResourceErrorDetail,
+    ) -> Self {
+        Self {
+            target_type,
+            input: input.into(),
+            error_detail,
+        }
+    }
+}
+
+impl_resource_id!(
+    AwsNetworkAclId,
+    "acl-",
+    "AWS Network ACL (Access Control List) ID",
+    "Network ACL"
+);
+impl_resource_id!(AwsAmiId, "ami-", "AWS AMI (Amazon Machine Image) ID", "AMI");
+impl_resource_id!(
+    AwsCustomerGatewayId,
+    "cgw-",
+    "AWS Customer Gateway ID",
+    "Customer Gateway"
+);
+impl_resource_id!(
+    AwsElasticIpId,
+    "eipalloc-",
+    "AWS Elastic IP ID",
+    "Elastic IP"
+);
+impl_resource_id!(
+    AwsElasticIpAssociationId,
+    "eipassoc-",
+    "AWS Elastic IP Association ID",
+    "Elastic IP Association"
+);
+impl_resource_id!(
+    AwsEfsFileSystemId,
+    "fs-",
+    "AWS EFS (Elastic File System) ID",
+    "EFS"
+);
+impl_resource_id!(
+    AwsEfsMountTargetId,
+    "fsmt-",
+    "AWS EFS Mount Target ID",
+    "EFS Mount Target"
+);
+impl_resource_id!(
+    AwsCloudFormationStackId,
+    "stack-",
+    "AWS CloudFormation Stack ID",
+    "CloudFormation Stack"
+);
+impl_resource_id!(
+    AwsElasticBeanstalkEnvironmentId,
+    "e-",
+    "AWS Elastic Beanstalk Environment ID",
+    "Elastic Beanstalk Environment"
+);
+impl_resource_id!(AwsInstanceId, "i-", "AWS EC2 Instance ID", "EC2 Instance");
+impl_resource_id!(
+    AwsInternetGatewayId,
+    "igw-",
+    "AWS Internet Gateway ID",
+    "Internet Gateway"
+);
+impl_resource_id!(AwsKeyPairId, "key-", "AWS Key Pair ID", "Key Pair");
+impl_resource_id!(
+    AwsLoadBalancerId,
+    "elbv2-",
+    "AWS Elastic Load Balancer ID",
+    "Elastic Load Balancer"
+);
+impl_resource_id!(AwsNatGatewayId, "nat-", "AWS NAT Gateway ID", "NAT Gateway");
+impl_resource_id!(
+    AwsNetworkInterfaceId,
+    "eni-",
+    "AWS Network Interface ID",
+    "Network Interface"
+);
+impl_resource_id!(
+    AwsPlacementGroupId,
+    "pg-",
+    "AWS Placement Group ID",
+    "Placement Group"
+);
+impl_resource_id!(
+    AwsRdsInstanceId,
+    "db-",
+    "AWS RDS Instance ID",
+    "RDS Instance"
+);
+impl_resource_id!(
+    AwsRedshiftClusterId,
+    "redshift-",
+    "AWS Redshift Cluster ID",
+    "Redshift Cluster"
+);
+impl_resource_id!(AwsRouteTableId, "rtb-", "AWS Route Table ID", "Route Table");
+impl_resource_id!(
+    AwsSecurityGroupId,
+    "sg-",
+    "AWS Security Group ID",
+    "Security Group"
+);
+impl_resource_id!(
+    AwsSnapshotId,
+    "snap-",
+    "AWS EBS Snapshot ID",
+    "EBS Snapshot"
+);
+impl_resource_id!(AwsSubnetId, "subnet-", "AWS VPC Subnet ID", "VPC Subnet");
+impl_resource_id!(
+    AwsTargetGroupId,
+    "tg-",
+    "AWS Target Group ID",
+    "Target Group"
+);
+impl_resource_id!(
+    AwsTransitGatewayAttachmentId,
+    "tgw-attach-",
+    "AWS Transit Gateway Attachment ID",
+    "Transit Gateway Attachment"
+);
+impl_resource_id!(
+    AwsTransitGatewayConnectPeerId,
+    "tgw-connect-peer-",
+    "AWS Transit Gateway Connect Peer ID",
+    "Transit Gateway Connect Peer"
+);
+impl_resource_id!(
+    AwsTransitGatewayMulticastDomainId,
+    "tgw-mcast-domain-",
+    "AWS Transit Gateway Multicast Domain ID",
+    "Transit Gateway Multicast Domain"
+);
+impl_resource_id!(
+    AwsTransitGatewayRouteTableId,
+    "tgw-rtb-",
+    "AWS Transit Gateway Route Table ID",
+    "Transit Gateway Route Table"
+);
+impl_resource_id!(
+    AwsTransitGatewayId,
+    "tgw-",
+    "AWS Transit Gateway ID",
+    "Transit Gateway"
+);
+impl_resource_id!(AwsVolumeId, "vol-", "AWS EBS Volume ID", "EBS Volume");
+impl_resource_id!(
+    AwsVpcId,
+    "vpc-",
+    "AWS VPC (Virtual Private Cloud) ID",
+    "VPC"
+);
+impl_resource_id!(
+    AwsVpnConnectionId,
+    "vpn-",
+    "AWS VPN Connection ID",
+    "VPN Connection"
+);
+impl_resource_id!(AwsVpnGatewayId, "vgw-", "AWS VPN Gateway ID", "VPN Gateway");
+
+macro_rules! impl_any_resource_id {
+    ($($variant:ident($type:ident) => $plural:ident),* $(,)?) => {
+        /// Any AWS resource ID in the general format, the type is detected by
+        /// the prefix
+        ///
+        /// The derived `Ord` is a total order: kinds in the declaration order,
+        /// then ids of the same kind by the unique part, 8 characters ones
+        /// first. See [`sort_natural`] for a human-friendly order.
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum AnyResourceId {
+            $(
+                #[doc = concat!("[`", stringify!($type), "`]")]
+                $variant($type),
+            )*
+        }
+
+        /// The resource type of an [`AnyResourceId`]
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum ResourceKind {
+            $(
+                #[doc = concat!("[`", stringify!($type), "`]")]
+                $variant,
+            )*
+        }
+
+        impl ResourceKind {
+            /// All the resource types, in the declaration order
+            #[must_use]
+            pub fn all() -> &'static [ResourceKind] {
+                &[$(ResourceKind::$variant),*]
+            }
+
+            /// Prefix of the resource type, e.g. `subnet-`
+            #[must_use]
+            pub fn prefix(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $type::PREFIX,)*
+                }
+            }
+
+            /// Name of the id type, e.g. `AwsSubnetId`
+            #[must_use]
+            pub fn type_name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => stringify!($type),)*
+                }
+            }
+        }
+
+        impl AnyResourceId {
+            /// Prefixes of all the known resource types
+            pub const PREFIXES: &'static [&'static str] = &[$($type::PREFIX),*];
+
+            /// The resource type of the id
+            #[must_use]
+            pub fn kind(&self) -> ResourceKind {
+                match self {
+                    $(Self::$variant(_) => ResourceKind::$variant,)*
+                }
+            }
+
+            /// Name of the inner id type, e.g. `AwsSubnetId`
+            #[must_use]
+            pub fn type_name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant(_) => stringify!($type),)*
+                }
+            }
+
+            fn unique(&self) -> &UniquePart {
+                match self {
+                    $(Self::$variant(id) => &id.0,)*
+                }
+            }
+        }
+
+        impl TryFrom<&str> for AnyResourceId {
+            type Error = $crate::Error;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                // The longest match wins, e.g. `tgw-attach-` over `tgw-`
+                let prefix = Self::PREFIXES
+                    .iter()
+                    .filter(|p| s.starts_with(*p))
+                    .max_by_key(|p| p.len())
+                    .copied();
+                match prefix {
+                    $(Some($type::PREFIX) => $type::try_from(s).map(Self::$variant),)*
+                    _ => Err(GeneralResourceError::new(
+                        short_type_name::<Self>(),
+                        s,
+                        GeneralResourceErrorDetail::UnknownPrefix,
+                    )
+                    .into()),
+                }
+            }
+        }
+
+        impl fmt::Display for AnyResourceId {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(Self::$variant(id) => id.fmt(f),)*
+                }
+            }
+        }
+
+        $(
+            impl From<$type> for AnyResourceId {
+                fn from(id: $type) -> Self {
+                    Self::$variant(id)
+                }
+            }
+
+            impl TryFrom<AnyResourceId> for $type {
+                type Error = $crate::Error;
+
+                fn try_from(id: AnyResourceId) -> Result<Self, Self::Error> {
+                    match id {
+                        AnyResourceId::$variant(id) => Ok(id),
+                        _ => Err(GeneralResourceError::new(
+                            short_type_name::<Self>(),
+                            id.to_string(),
+                            GeneralResourceErrorDetail::WrongPrefix(Self::PREFIX),
+                        )
+                        .into()),
+                    }
+                }
+            }
+        )*
+
+        impl ResourceInventory {
+            $(
+                #[doc = concat!("The [`", stringify!($type), "`] ids of the inventory")]
+                pub fn $plural(&self) -> impl Iterator<Item = &$type> {
+                    self.ids.iter().filter_map(|id| match id {
+                        AnyResourceId::$variant(id) => Some(id),
+                        _ => None,
+                    })
+                }
+            )*
+        }
+    };
+}
+
+impl_any_resource_id!(
+    NetworkAcl(AwsNetworkAclId) => network_acls,
+    Ami(AwsAmiId) => amis,
+    CustomerGateway(AwsCustomerGatewayId) => customer_gateways,
+    ElasticIp(AwsElasticIpId) => elastic_ips,
+    ElasticIpAssociation(AwsElasticIpAssociationId) => elastic_ip_associations,
+    EfsFileSystem(AwsEfsFileSystemId) => efs_file_systems,
+    EfsMountTarget(AwsEfsMountTargetId) => efs_mount_targets,
+    CloudFormationStack(AwsCloudFormationStackId) => cloud_formation_stacks,
+    ElasticBeanstalkEnvironment(AwsElasticBeanstalkEnvironmentId) => elastic_beanstalk_environments,
+    Instance(AwsInstanceId) => instances,
+    InternetGateway(AwsInternetGatewayId) => internet_gateways,
+    KeyPair(AwsKeyPairId) => key_pairs,
+    LoadBalancer(AwsLoadBalancerId) => load_balancers,
+    NatGateway(AwsNatGatewayId) => nat_gateways,
+    NetworkInterface(AwsNetworkInterfaceId) => network_interfaces,
+    PlacementGroup(AwsPlacementGroupId) => placement_groups,
+    RdsInstance(AwsRdsInstanceId) => rds_instances,
+    RedshiftCluster(AwsRedshiftClusterId) => redshift_clusters,
+    RouteTable(AwsRouteTableId) => route_tables,
+    SecurityGroup(AwsSecurityGroupId) => security_groups,
+    Snapshot(AwsSnapshotId) => snapshots,
+    Subnet(AwsSubnetId) => subnets,
+    TargetGroup(AwsTargetGroupId) => target_groups,
+    TransitGatewayAttachment(AwsTransitGatewayAttachmentId) => transit_gateway_attachments,
+    TransitGatewayConnectPeer(AwsTransitGatewayConnectPeerId) => transit_gateway_connect_peers,
+    TransitGatewayMulticastDomain(AwsTransitGatewayMulticastDomainId) => transit_gateway_multicast_domains,
+    TransitGatewayRouteTable(AwsTransitGatewayRouteTableId) => transit_gateway_route_tables,
+    TransitGateway(AwsTransitGatewayId) => transit_gateways,
+    Volume(AwsVolumeId) => volumes,
+    Vpc(AwsVpcId) => vpcs,
+    VpnConnection(AwsVpnConnectionId) => vpn_connections,
+    VpnGateway(AwsVpnGatewayId) => vpn_gateways,
+);
+
+/// A collection of ids of mixed types with typed views, e.g. for inventory
+/// tools
+///
+/// ```
+/// use aws_resource_id::ResourceInventory;
+///
+/// let inventory = ResourceInventory::try_from(vec!["ami-1234abcd", "i-1234abcd"]).unwrap();
+/// assert_eq!(inventory.len(), 2);
+/// assert_eq!(inventory.amis().count(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ResourceInventory {
+    ids: Vec<AnyResourceId>,
+}
+
+impl ResourceInventory {
+    /// Creates an empty inventory
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an id, either [`AnyResourceId`] or a concrete one
+    pub fn push(&mut self, id: impl Into<AnyResourceId>) {
+        self.ids.push(id.into());
+    }
+
+    /// The number of ids
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Checks if there are no ids
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Iterates over all the ids in the insertion order
+    pub fn iter(&self) -> core::slice::Iter<'_, AnyResourceId> {
+        self.ids.iter()
+    }
+}
+
+/// Fails on the first invalid id
+impl TryFrom<Vec<&str>> for ResourceInventory {
+    type Error = crate::Error;
+
+    fn try_from(ids: Vec<&str>) -> Result<Self, Self::Error> {
+        ids.into_iter().map(AnyResourceId::try_from).collect()
+    }
+}
+
+impl FromIterator<AnyResourceId> for ResourceInventory {
+    fn from_iter<I: IntoIterator<Item = AnyResourceId>>(iter: I) -> Self {
+        Self {
+            ids: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a ResourceInventory {
+    type Item = &'a AnyResourceId;
+    type IntoIter = core::slice::Iter<'a, AnyResourceId>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for ResourceInventory {
+    type Item = AnyResourceId;
+    type IntoIter = alloc::vec::IntoIter<AnyResourceId>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ids.into_iter()
+    }
+}
+
+/// A list of ids of one type, validating strings as they're pushed
+///
+/// ```
+/// use aws_resource_id::{AwsVpcId, ResourceIdVec};
+///
+/// let mut vpcs = ResourceIdVec::<AwsVpcId>::new();
+/// vpcs.push_str("vpc-1234abcd").unwrap();
+/// assert!(vpcs.push_str("subnet-1234abcd").is_err());
+/// assert_eq!(vpcs.len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResourceIdVec<T>(Vec<T>);
+
+impl<T> ResourceIdVec<T> {
+    /// Creates an empty list
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Adds an id
+    pub fn push(&mut self, id: T) {
+        self.0.push(id);
+    }
+
+    /// Parses and adds an id, the list is left unchanged on errors
+    pub fn push_str(&mut self, s: &str) -> Result<(), crate::Error>
+    where
+        T: FromStr<Err = crate::Error>,
+    {
+        self.0.push(s.parse()?);
+        Ok(())
+    }
+
+    /// The underlying vector
+    #[must_use]
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> Default for ResourceIdVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> core::ops::Deref for ResourceIdVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> Extend<T> for ResourceIdVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T> FromIterator<T> for ResourceIdVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T> From<Vec<T>> for ResourceIdVec<T> {
+    fn from(ids: Vec<T>) -> Self {
+        Self(ids)
+    }
+}
+
+impl<T> From<ResourceIdVec<T>> for Vec<T> {
+    fn from(ids: ResourceIdVec<T>) -> Self {
+        ids.0
+    }
+}
+
+impl<T> IntoIterator for ResourceIdVec<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ResourceIdVec<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Groups ids by their resource type, preserving the order within each group
+#[cfg(feature = "std")]
+#[must_use]
+pub fn group_by_kind(ids: &[AnyResourceId]) -> HashMap<ResourceKind, Vec<AnyResourceId>> {
+    let mut groups: HashMap<_, Vec<_>> = HashMap::new();
+    for id in ids {
+        groups.entry(id.kind()).or_default().push(*id);
+    }
+    groups
+}
+
+impl AnyResourceId {
+    /// Compares ids naturally: by the type name alphabetically, then the ids
+    /// with all digits unique parts numerically, e.g. `i-20000000` goes before
+    /// `i-10000000000000000`, then the rest by the unique part
+    #[must_use]
+    pub fn cmp_natural(&self, other: &Self) -> core::cmp::Ordering {
+        self.natural_key().cmp(&other.natural_key())
+    }
+
+    /// The key of [`Self::cmp_natural`]: the type name, whether the unique
+    /// part isn't a number, the number of its significant digits, the digits
+    /// themselves, and the unique part to order equal numbers
+    ///
+    /// A single key per id keeps the order total, unlike deciding on the
+    /// numeric comparison per pair of ids.
+    fn natural_key(&self) -> (&'static str, bool, usize, [u8; 17], [u8; 17]) {
+        // Unique parts are ascii alphanumeric, so the zero padding orders a
+        // shorter part before a longer one, same as comparing the slices
+        fn padded(s: &[u8]) -> [u8; 17] {
+            let mut arr = [0u8; 17];
+            arr[..s.len()].copy_from_slice(s);
+            arr
+        }
+
+        let unique = self.unique();
+        let unique = unique.as_slice();
+        if unique.iter().all(u8::is_ascii_digit) {
+            let digits = &unique[unique.iter().take_while(|c| **c == b'0').count()..];
+            (
+                self.type_name(),
+                false,
+                digits.len(),
+                padded(digits),
+                padded(unique),
+            )
+        } else {
+            (self.type_name(), true, 0, [0; 17], padded(unique))
+        }
+    }
+}
+
+/// Sorts ids by [`AnyResourceId::cmp_natural`], grouping them by type
+pub fn sort_natural(ids: &mut [AnyResourceId]) {
+    ids.sort_by_cached_key(AnyResourceId::natural_key);
+}
+
+/// Positions of an id parts from [`analyze`], byte ranges of the input
+#[derive(Debug)]
+pub struct IdAnalysis {
+    /// The prefix including the hyphen, e.g. `0..4` for `ami-1234abcd`, it's
+    /// the text up to the last hyphen if the prefix isn't a known one
+    pub prefix: Option<Range<usize>>,
+    /// The unique part after the prefix
+    pub unique: Option<Range<usize>>,
+    /// The first problem found and the range it applies to, the checks go in
+    /// the parser order, so it's the problem parsing the id reports
+    pub error: Option<(Range<usize>, GeneralResourceErrorDetail)>,
+}
+
+impl IdAnalysis {
+    /// Checks if the input is a valid id of a known type
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Splits a general id into its parts without knowing the type in advance,
+/// e.g. for inline diagnostics in editors
+///
+/// ```
+/// use aws_resource_id::analyze;
+///
+/// let analysis = analyze("ami-1234_bcd");
+/// assert_eq!(analysis.prefix, Some(0..4));
+/// assert_eq!(analysis.unique, Some(4..12));
+/// assert_eq!(analysis.error.unwrap().0, 8..9);
+/// ```
+#[must_use]
+pub fn analyze(s: &str) -> IdAnalysis {
+    let known = AnyResourceId::PREFIXES
+        .iter()
+        .filter(|p| s.starts_with(*p))
+        .max_by_key(|p| p.len());
+    let Some(prefix_len) = known
+        .map(|p| p.len())
+        .or_else(|| s.rfind('-').map(|i| i + 1))
+    else {
+        return IdAnalysis {
+            prefix: None,
+            unique: None,
+            error: Some((0..s.len(), GeneralResourceErrorDetail::InvalidPrefix)),
+        };
+    };
+    let prefix = 0..prefix_len;
+    let unique = prefix_len..s.len();
+    let error = if known.is_none() {
+        Some((prefix.clone(), GeneralResourceErrorDetail::UnknownPrefix))
+    } else if unique.len() != 8 && unique.len() != 17 {
+        Some((
+            unique.clone(),
+            GeneralResourceErrorDetail::IdLength(unique.len()),
+        ))
+    } else if let Some((i, c)) = s[unique.clone()]
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_alphanumeric())
+    {
+        let start = prefix_len + i;
+        Some((
+            start..start + c.len_utf8(),
+            GeneralResourceErrorDetail::NonAsciiAlphanumeric,
+        ))
+    } else {
+        None
+    };
+    IdAnalysis {
+        prefix: Some(prefix),
+        unique: Some(unique),
+        error,
+    }
+}
+
+impl TryFrom<String> for AnyResourceId {
+    type Error = crate::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl TryFrom<&String> for AnyResourceId {
+    type Error = crate::Error;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl FromStr for AnyResourceId {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl From<AnyResourceId> for String {
+    fn from(value: AnyResourceId) -> Self {
+        value.to_string()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for AnyResourceId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AnyResourceId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(AnyResourceIdVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct AnyResourceIdVisitor;
+
+#[cfg(feature = "serde")]
+impl serde::de::Visitor<'_> for AnyResourceIdVisitor {
+    type Value = AnyResourceId;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string AWS resource id")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        AnyResourceId::try_from(&*crate::lenient_input(v)).map_err(E::custom)
+    }
+}
+
+/// An AWS resource ID in the general format with an arbitrary prefix, e.g.
+/// for resource types not covered by the typed ids
+///
+/// Unlike the typed ids, it stores the prefix on the heap, so it isn't `Copy`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RawResourceId {
+    prefix: String,
+    unique: UniquePart,
+}
+
+impl RawResourceId {
+    /// Creates an id from its prefix (including the trailing hyphen, e.g.
+    /// `ami-`) and unique part
+    ///
+    /// The prefix must be lowercase ascii alphanumeric words each followed by
+    /// a hyphen, and at most 17 characters long.
+    pub fn from_parts(prefix: &str, unique: &str) -> Result<Self, crate::Error> {
+        let error = |detail| {
+            GeneralResourceError::new(
+                short_type_name::<Self>(),
+                format!("{prefix}{unique}"),
+                detail,
+            )
+        };
+        if !is_valid_prefix(prefix) {
+            return Err(error(GeneralResourceErrorDetail::InvalidPrefix).into());
+        }
+        let unique = UniquePart::parse(unique.as_bytes()).map_err(error)?;
+        Ok(Self {
+            prefix: prefix.into(),
+            unique,
+        })
+    }
+
+    /// The id prefix, including the trailing hyphen
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+}
+
+impl TryFrom<&str> for RawResourceId {
+    type Error = crate::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        // The unique part never contains hyphens, so the prefix ends at the last one
+        let split = s.rfind('-').map_or(0, |i| i + 1);
+        Self::from_parts(&s[..split], &s[split..])
+    }
+}
+
+impl TryFrom<String> for RawResourceId {
+    type Error = crate::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl TryFrom<&String> for RawResourceId {
+    type Error = crate::Error;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl FromStr for RawResourceId {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for RawResourceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.prefix)?;
+        f.write_str(core::str::from_utf8(self.unique.as_slice()).unwrap_or_default())
+    }
+}
+
+impl fmt::Debug for RawResourceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(short_type_name::<Self>())
+            .field(&self.to_string())
+            .finish()
+    }
+}
+
+impl From<RawResourceId> for String {
+    fn from(value: RawResourceId) -> Self {
+        value.to_string()
+    }
+}
+
+/// A resource id pattern with an optional trailing `*` wildcard, as used in
+/// IAM policy ARNs, e.g. `i-*` or `i-0abc*`
+///
+/// ```
+/// use aws_resource_id::{AwsInstanceId, IdPattern};
+///
+/// let pattern: IdPattern = "i-0abc*".parse().unwrap();
+/// let id: AwsInstanceId = "i-0abcdef0123456789".parse().unwrap();
+/// assert!(pattern.matches(&id));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdPattern {
+    prefix: String,
+    wildcard: bool,
+}
+
+impl IdPattern {
+    /// Checks if the id matches the pattern: starts with the part before the
+    /// wildcard, or equals the whole pattern if there's no wildcard
+    ///
+    /// The pattern is compared with the id prefix and unique part in place,
+    /// without formatting the id.
+    #[must_use]
+    pub fn matches<T: ResourceId>(&self, id: &T) -> bool {
+        let pattern = self.prefix.as_bytes();
+        let prefix = T::PREFIX.as_bytes();
+        if pattern.len() <= prefix.len() {
+            // The id is longer than its prefix, so only a wildcard matches
+            return self.wildcard && prefix.starts_with(pattern);
+        }
+        let (pattern_prefix, pattern_unique) = pattern.split_at(prefix.len());
+        let unique = id.unique_bytes();
+        pattern_prefix == prefix
+            && if self.wildcard {
+                unique.starts_with(pattern_unique)
+            } else {
+                unique == pattern_unique
+            }
+    }
+}
+
+impl TryFrom<&str> for IdPattern {
+    type Error = crate::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (prefix, wildcard) = match s.strip_suffix('*') {
+            Some(prefix) => (prefix, true),
+            None => (s, false),
+        };
+        if prefix.is_empty()
+            || !prefix
+                .bytes()
+                .all(|c| c.is_ascii_alphanumeric() || c == b'-')
+        {
+            return Err(GeneralResourceError::new(
+                short_type_name::<Self>(),
+                s,
+                GeneralResourceErrorDetail::InvalidPattern,
+            )
+            .into());
+        }
+        Ok(Self {
+            prefix: prefix.into(),
+            wildcard,
+        })
+    }
+}
+
+impl TryFrom<String> for IdPattern {
+    type Error = crate::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl TryFrom<&String> for IdPattern {
+    type Error = crate::Error;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl FromStr for IdPattern {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for IdPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.prefix)?;
+        if self.wildcard {
+            f.write_str("*")?;
+        }
+        Ok(())
+    }
+}
+
+/// Either an id or the `*` wildcard, as used in IAM-style policy fields
+///
+/// Wrap it into an `Option` for fields that can also be absent or `null`:
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() {
+/// use aws_resource_id::{AwsInstanceId, IdOrWildcard};
+///
+/// #[derive(serde::Deserialize)]
+/// struct Statement {
+///     #[serde(default)]
+///     instance: Option<IdOrWildcard<AwsInstanceId>>,
+/// }
+///
+/// let s: Statement = serde_json::from_str(r#"{"instance": "*"}"#).unwrap();
+/// assert_eq!(s.instance, Some(IdOrWildcard::Wildcard));
+/// let s: Statement = serde_json::from_str("{}").unwrap();
+/// assert_eq!(s.instance, None);
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IdOrWildcard<T> {
+    /// A specific id
+    Id(T),
+    /// The `*` wildcard, matching any id
+    Wildcard,
+}
+
+impl<T: PartialEq> IdOrWildcard<T> {
+    /// Checks if the id is the specific one or the value is the wildcard
+    #[must_use]
+    pub fn matches(&self, id: &T) -> bool {
+        match self {
+            Self::Id(own) => own == id,
+            Self::Wildcard => true,
+        }
+    }
+}
+
+impl<T> TryFrom<&str> for IdOrWildcard<T>
+where
+    T: FromStr<Err = crate::Error>,
+{
+    type Error = crate::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        if s == "*" {
+            Ok(Self::Wildcard)
+        } else {
+            s.parse().map(Self::Id)
+        }
+    }
+}
+
+impl<T> FromStr for IdOrWildcard<T>
+where
+    T: FromStr<Err = crate::Error>,
+{
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for IdOrWildcard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Id(id) => id.fmt(f),
+            Self::Wildcard => f.write_str("*"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: fmt::Display> serde::Serialize for IdOrWildcard<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for IdOrWildcard<T>
+where
+    T: FromStr<Err = crate::Error>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <alloc::borrow::Cow<'_, str> as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(&*crate::lenient_input(&s)).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn ami(s: &str) -> AwsAmiId {
+        AwsAmiId::try_from(s).unwrap()
+    }
+
+    #[test]
+    fn test_const_prefix() {
+        const P: &str = AwsAmiId::PREFIX;
+        assert_eq!(P, "ami-");
+    }
+
+    #[test]
+    fn test_examples() {
+        macro_rules! assert_example {
+            ($($type:ident),*) => {$(
+                let example = $type::EXAMPLE.to_string();
+                assert_eq!(example, format!("{}1234567890abcdef0", $type::PREFIX));
+                assert_eq!($type::try_from(example).unwrap(), $type::EXAMPLE);
+                let any = AnyResourceId::from($type::EXAMPLE);
+                assert_eq!($type::try_from(any).unwrap(), $type::EXAMPLE);
+            )*};
+        }
+
+        assert_example!(
+            AwsNetworkAclId,
+            AwsAmiId,
+            AwsCustomerGatewayId,
+            AwsElasticIpId,
+            AwsElasticIpAssociationId,
+            AwsEfsFileSystemId,
+            AwsEfsMountTargetId,
+            AwsCloudFormationStackId,
+            AwsElasticBeanstalkEnvironmentId,
+            AwsInstanceId,
+            AwsInternetGatewayId,
+            AwsKeyPairId,
+            AwsLoadBalancerId,
+            AwsNatGatewayId,
+            AwsNetworkInterfaceId,
+            AwsPlacementGroupId,
+            AwsRdsInstanceId,
+            AwsRedshiftClusterId,
+            AwsRouteTableId,
+            AwsSecurityGroupId,
+            AwsSnapshotId,
+            AwsSubnetId,
+            AwsTargetGroupId,
+            AwsTransitGatewayAttachmentId,
+            AwsTransitGatewayConnectPeerId,
+            AwsTransitGatewayMulticastDomainId,
+            AwsTransitGatewayRouteTableId,
+            AwsTransitGatewayId,
+            AwsVolumeId,
+            AwsVpcId,
+            AwsVpnConnectionId,
+            AwsVpnGatewayId
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data: Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&data);
+        let id = AwsAmiId::arbitrary_short(&mut u).unwrap();
+        assert_eq!(id.to_string().len(), "ami-".len() + 8);
+        assert_eq!(AwsAmiId::try_from(id.to_string()).unwrap(), id);
+        let id = AwsAmiId::arbitrary_long(&mut u).unwrap();
+        assert_eq!(id.to_string().len(), "ami-".len() + 17);
+        assert_eq!(AwsAmiId::try_from(id.to_string()).unwrap(), id);
+
+        for _ in 0..5 {
+            let id = AwsSubnetId::arbitrary(&mut u).unwrap();
+            assert_eq!(AwsSubnetId::try_from(id.to_string()).unwrap(), id);
+        }
+    }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn test_ct_eq() {
+        let pairs = [
+            ("ami-12345678", "ami-12345678"),
+            ("ami-12345678", "ami-12345679"),
+            ("ami-12345678", "ami-1234567890abcdef0"),
+            ("ami-1234567890abcdef0", "ami-1234567890abcdef0"),
+        ];
+        for (a, b) in pairs {
+            let (a, b) = (ami(a), ami(b));
+            assert_eq!(a.ct_eq(&b), a == b, "{a} {b}");
+        }
+    }
+
+    #[test]
+    fn test_parse_prefix_of() {
+        let (id, rest) = AwsInstanceId::parse_prefix_of("i-1234567890abcdef0 rest").unwrap();
+        assert_eq!(id.to_string(), "i-1234567890abcdef0");
+        assert_eq!(rest, " rest");
+        let (id, rest) = AwsInstanceId::parse_prefix_of("i-1234abcd,vpc-1234abcd").unwrap();
+        assert_eq!(id.to_string(), "i-1234abcd");
+        assert_eq!(rest, ",vpc-1234abcd");
+        let (_, rest) = AwsInstanceId::parse_prefix_of("i-1234abcd").unwrap();
+        assert_eq!(rest, "");
+
+        assert!(AwsInstanceId::parse_prefix_of("i-1234abcde rest").is_err());
+        assert!(AwsInstanceId::parse_prefix_of("vpc-1234abcd rest").is_err());
+        assert!(AwsInstanceId::parse_prefix_of("i-").is_err());
+    }
+
+    #[test]
+    fn test_reinterpret_unique_as() {
+        let instance = AwsInstanceId::try_from("i-1234567890abcdef0").unwrap();
+        let volume: AwsVolumeId = instance.reinterpret_unique_as();
+        assert_eq!(volume.to_string(), "vol-1234567890abcdef0");
+        assert_eq!(volume.reinterpret_unique_as::<AwsInstanceId>(), instance);
+        assert_eq!(<AwsVolumeId as ResourceId>::PREFIX, "vol-");
+    }
+
+    #[test]
+    fn test_display_alternate() {
+        let id = ami("ami-12345678");
+        assert_eq!(format!("{id:#}"), "AMI ami-12345678");
+        assert_eq!(format!("{id}"), "ami-12345678");
+        assert_eq!(
+            format!("{:#}", AwsInstanceId::try_from("i-1234abcd").unwrap()),
+            "EC2 Instance i-1234abcd"
+        );
+        assert_eq!(AwsVpcId::LABEL, "VPC");
+        assert_eq!(
+            format!("{:#}", AnyResourceId::try_from("vpc-1234abcd").unwrap()),
+            "VPC vpc-1234abcd"
+        );
+    }
+
+    #[test]
+    fn test_try_from_bytes() {
+        assert_eq!(
+            AwsAmiId::try_from(b"ami-12345678".as_slice()).unwrap(),
+            ami("ami-12345678")
+        );
+        assert_eq!(
+            AwsAmiId::try_from(b"ami-1234567890abcdef0".to_vec()).unwrap(),
+            ami("ami-1234567890abcdef0")
+        );
+
+        let err = AwsAmiId::try_from(b"ami-1234567!".to_vec()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to initialize AwsAmiId from \"ami-1234567!\": the unique part contains non ascii alphanumeric characters"
+        );
+        let err = AwsAmiId::try_from(b"ami-1234567\xff".to_vec()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to initialize AwsAmiId from \"ami-1234567\u{fffd}\": the unique part contains non ascii alphanumeric characters"
+        );
+        assert!(AwsAmiId::try_from(b"vpc-12345678".as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_display_len() {
+        const MAX: usize = AwsSubnetId::max_display_len();
+        assert_eq!(MAX, "subnet-".len() + 17);
+        let _buf = [0u8; AwsSubnetId::max_display_len()];
+
+        let id = AwsSubnetId::try_from("subnet-1234abcd").unwrap();
+        assert_eq!(id.display_len(), id.to_string().len());
+        let id = AwsSubnetId::try_from("subnet-1a2b3c4d5e6f7j8h9").unwrap();
+        assert_eq!(id.display_len(), id.to_string().len());
+        assert_eq!(id.display_len(), MAX);
+    }
+
+    #[test]
+    fn test_prefix_len() {
+        assert_eq!(
+            AwsTransitGatewayAttachmentId::PREFIX_LEN,
+            "tgw-attach-".len()
+        );
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(ami("ami-12345678"), ami("ami-12345678"));
+        assert_ne!(ami("ami-12345678"), ami("ami-abcdefgh"));
+    }
+
+    #[test]
+    fn test_hash_matches_str() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let hasher = RandomState::new();
+        assert_eq!(
+            hasher.hash_one(ami("ami-12345678")),
+            hasher.hash_one("ami-12345678")
+        );
+        let id = AwsTransitGatewayAttachmentId::try_from("tgw-attach-1a2b3c4d5e6f7j8h9").unwrap();
+        assert_eq!(
+            hasher.hash_one(id),
+            hasher.hash_one("tgw-attach-1a2b3c4d5e6f7j8h9")
+        );
+    }
+
+    #[test]
+    fn test_mask() {
+        assert_eq!(ami("ami-12345678").mask(), "ami-12…78");
+        assert_eq!(ami("ami-1234567890abcdef0").mask(), "ami-12…f0");
+    }
+
+    #[test]
+    fn test_cmp_by_id() {
+        use std::cmp::Ordering;
+
+        let long = AwsInstanceId::try_from("i-00000000000000000").unwrap();
+        let short = AwsInstanceId::try_from("i-zzzzzzzz").unwrap();
+        assert_eq!(long.cmp_by_id(&short), Ordering::Less);
+        assert_eq!(long.cmp(&short), Ordering::Greater);
+        assert_eq!(short.cmp_by_id(&short), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_fmt_display() {
+        assert_eq!(format!("{}", ami("ami-12345678")), "ami-12345678");
+    }
+
+    #[cfg(not(feature = "redact-debug"))]
+    #[test]
+    fn test_fmt_debug() {
+        assert_eq!(
+            format!("{:?}", ami("ami-12345678")),
+            r#"AwsAmiId("ami-12345678")"#
+        );
+    }
+
+    #[cfg(feature = "compact_str")]
+    #[test]
+    fn test_to_compact() {
+        let id = ami("ami-1a2b3c4d5e6f7j8h9");
+        assert_eq!(id.to_compact(), id.to_string());
+        assert!(!id.to_compact().is_heap_allocated());
+        let id = AwsTransitGatewayAttachmentId::try_from("tgw-attach-1a2b3c4d5e6f7j8h9").unwrap();
+        assert_eq!(id.to_compact(), id.to_string());
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_to_heapless() {
+        assert_eq!(ami("ami-12345678").to_heapless(), "ami-12345678");
+        let id =
+            AwsTransitGatewayConnectPeerId::try_from("tgw-connect-peer-1a2b3c4d5e6f7j8h9").unwrap();
+        assert_eq!(id.to_string().len(), MAX_ID_LEN);
+        assert_eq!(id.to_heapless().as_str(), id.to_string());
+    }
+
+    #[cfg(feature = "redact-debug")]
+    #[test]
+    fn test_fmt_debug_redacted() {
+        assert_eq!(format!("{:?}", ami("ami-12345678")), "AwsAmiId(<redacted>)");
+        assert_eq!(ami("ami-12345678").to_string(), "ami-12345678");
+    }
+
+    #[test]
+    fn test_into_string() {
+        let s: String = ami("ami-12345678").into();
+        assert_eq!(s, "ami-12345678");
+    }
+
+    #[test]
+    fn test_tryfrom_str() {
+        assert!(AwsAmiId::try_from("ami-12345678").is_ok());
+    }
+
+    #[test]
+    fn test_tryfrom_string() {
+        assert!(AwsAmiId::try_from("ami-12345678".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_tryfrom_refstring() {
+        assert!(AwsAmiId::try_from(&"ami-12345678".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_fromstr() {
+        assert!("ami-12345678".parse::<AwsAmiId>().is_ok(),);
+        assert!("ami-12345678".to_string().parse::<AwsAmiId>().is_ok(),);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_string(&ami("ami-12345678")).unwrap(),
+            "\"ami-12345678\""
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_compact() {
+        use crate::Compact;
+
+        let id = ami("ami-1234567890abcdef0");
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&Compact(id), &mut cbor).unwrap();
+        // A byte string header followed by the unique part only
+        assert_eq!(cbor.len(), 1 + 17);
+        assert_eq!(&cbor[1..], b"1234567890abcdef0");
+        assert_eq!(
+            ciborium::from_reader::<Compact<AwsAmiId>, _>(&cbor[..]).unwrap(),
+            Compact(id)
+        );
+
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&ciborium::Value::Bytes(b"1234".to_vec()), &mut cbor).unwrap();
+        let err = ciborium::from_reader::<Compact<AwsAmiId>, _>(&cbor[..]).unwrap_err();
+        assert!(err.to_string().contains("not 4 characters long"), "{err}");
+
+        assert_eq!(
+            serde_json::to_string(&Compact(id)).unwrap(),
+            "\"ami-1234567890abcdef0\""
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_binary_string_form() {
+        // Binary formats store the id string, as before the compact form was
+        // added, so the previously persisted data stays readable
+        let id = ami("ami-1234567890abcdef0");
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&id, &mut cbor).unwrap();
+        let mut string_cbor = Vec::new();
+        ciborium::into_writer(&"ami-1234567890abcdef0", &mut string_cbor).unwrap();
+        assert_eq!(cbor, string_cbor);
+        assert_eq!(ciborium::from_reader::<AwsAmiId, _>(&cbor[..]).unwrap(), id);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize() {
+        assert_eq!(
+            serde_json::from_str::<AwsAmiId>("\"ami-12345678\"").unwrap(),
+            ami("ami-12345678"),
+        );
+    }
+
+    #[test]
+    fn test_any_resource_id() {
+        assert_eq!(
+            AnyResourceId::try_from("subnet-1234abcd").unwrap(),
+            AnyResourceId::Subnet(AwsSubnetId::try_from("subnet-1234abcd").unwrap())
+        );
+        assert_eq!(
+            AnyResourceId::try_from("tgw-1234abcd").unwrap(),
+            AnyResourceId::TransitGateway(AwsTransitGatewayId::try_from("tgw-1234abcd").unwrap())
+        );
+        assert_eq!(
+            AnyResourceId::try_from("tgw-attach-1234abcd").unwrap(),
+            AnyResourceId::TransitGatewayAttachment(
+                AwsTransitGatewayAttachmentId::try_from("tgw-attach-1234abcd").unwrap()
+            )
+        );
+        assert_eq!(
+            AnyResourceId::try_from("vpc-1234abcd").unwrap().to_string(),
+            "vpc-1234abcd"
+        );
+        assert_eq!(
+            AnyResourceId::try_from("tgw-rtb-1234abcd")
+                .unwrap()
+                .type_name(),
+            "AwsTransitGatewayRouteTableId"
+        );
+        assert_eq!(
+            AnyResourceId::try_from("vpc-1234abcd").unwrap().type_name(),
+            "AwsVpcId"
+        );
+        assert!(AnyResourceId::try_from("vpc-123").is_err());
+        assert!(AnyResourceId::PREFIXES
+            .iter()
+            .all(|p| AnyResourceId::try_from(format!("{p}1234abcd")).is_ok()));
+    }
+
+    #[test]
+    fn test_any_resource_id_tgw_prefixes() {
+        for (input, type_name) in [
+            ("tgw-1234abcd", "AwsTransitGatewayId"),
+            ("tgw-attach-1234abcd", "AwsTransitGatewayAttachmentId"),
+            (
+                "tgw-connect-peer-1234abcd",
+                "AwsTransitGatewayConnectPeerId",
+            ),
+            (
+                "tgw-mcast-domain-1234abcd",
+                "AwsTransitGatewayMulticastDomainId",
+            ),
+            ("tgw-rtb-1234abcd", "AwsTransitGatewayRouteTableId"),
+        ] {
+            let id = AnyResourceId::try_from(input).unwrap();
+            assert_eq!(id.type_name(), type_name, "{input}");
+            assert_eq!(id.to_string(), input);
+        }
+        // Every `tgw-*` prefix dispatches to its own type
+        let tgw: Vec<_> = AnyResourceId::PREFIXES
+            .iter()
+            .filter(|p| p.starts_with("tgw-"))
+            .collect();
+        assert_eq!(tgw.len(), 5);
+        for p in tgw {
+            let id = AnyResourceId::try_from(format!("{p}1a2b3c4d5e6f7j8h9")).unwrap();
+            assert_eq!(id.to_string(), format!("{p}1a2b3c4d5e6f7j8h9"));
+        }
+        // A near-miss of a longer prefix falls back to the plain `tgw-` type
+        // and fails there instead of being misclassified
+        assert!(AnyResourceId::try_from("tgw-rtc-1234abcd").is_err());
+    }
+
+    #[test]
+    fn test_resource_inventory() {
+        let inventory = ResourceInventory::try_from(vec![
+            "ami-1234abcd",
+            "i-1234abcd",
+            "ami-1234567890abcdef0",
+            "vpc-1234abcd",
+        ])
+        .unwrap();
+        assert_eq!(inventory.len(), 4);
+        let amis: Vec<String> = inventory.amis().map(ToString::to_string).collect();
+        assert_eq!(amis, ["ami-1234abcd", "ami-1234567890abcdef0"]);
+        assert_eq!(inventory.instances().count(), 1);
+        assert_eq!(inventory.vpcs().count(), 1);
+        assert_eq!(inventory.subnets().count(), 0);
+
+        let all: Vec<String> = (&inventory).into_iter().map(ToString::to_string).collect();
+        assert_eq!(all[1], "i-1234abcd");
+        assert_eq!(inventory.into_iter().count(), 4);
+
+        assert!(ResourceInventory::try_from(vec!["ami-1234abcd", "???"]).is_err());
+        assert!(ResourceInventory::new().is_empty());
+    }
+
+    #[test]
+    fn test_downcast_wrong_kind() {
+        let any = AnyResourceId::from(AwsVpcId::EXAMPLE);
+        assert_eq!(any, AnyResourceId::Vpc(AwsVpcId::EXAMPLE));
+        let err = AwsSubnetId::try_from(any).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to initialize AwsSubnetId from \"vpc-1234567890abcdef0\": incorrect prefix, expected \"subnet-\""
+        );
+
+        let mut inventory = ResourceInventory::new();
+        inventory.push(AwsVpcId::EXAMPLE);
+        inventory.push(any);
+        assert_eq!(inventory.vpcs().count(), 2);
+    }
+
+    #[test]
+    fn test_sort_natural() {
+        let mut ids: Vec<AnyResourceId> = [
+            "i-10000000000000000",
+            "vpc-1234abcd",
+            "i-20000000",
+            "ami-1234abcd",
+            "i-abcd1234",
+            "i-00000000000000003",
+        ]
+        .into_iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        sort_natural(&mut ids);
+        let sorted: Vec<String> = ids.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            sorted,
+            [
+                "ami-1234abcd",
+                "i-00000000000000003",
+                "i-20000000",
+                "i-10000000000000000",
+                "i-abcd1234",
+                "vpc-1234abcd",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cmp_natural_transitive() {
+        let ids: Vec<AnyResourceId> = [
+            "i-20000000",
+            "i-10000000000000000",
+            "i-15aaaaaa",
+            "i-00000003",
+            "i-00000000000000003",
+            "i-3aaaaaaa",
+            "i-aaaaaaaa",
+            "i-1a2b3c4d5e6f7a8b9",
+            "i-99999999999999999",
+            "vpc-20000000",
+        ]
+        .into_iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        for a in &ids {
+            for b in &ids {
+                assert_eq!(a.cmp_natural(b), b.cmp_natural(a).reverse(), "{a} {b}");
+                for c in &ids {
+                    if a.cmp_natural(b).is_le() && b.cmp_natural(c).is_le() {
+                        assert!(a.cmp_natural(c).is_le(), "{a} <= {b} <= {c}");
+                    }
+                }
+            }
+        }
+
+        let mut sorted = ids.clone();
+        sort_natural(&mut sorted);
+        let sorted: Vec<String> = sorted.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            sorted,
+            [
+                "i-00000000000000003",
+                "i-00000003",
+                "i-20000000",
+                "i-10000000000000000",
+                "i-99999999999999999",
+                "i-15aaaaaa",
+                "i-1a2b3c4d5e6f7a8b9",
+                "i-3aaaaaaa",
+                "i-aaaaaaaa",
+                "vpc-20000000",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resource_kind() {
+        for kind in ResourceKind::all() {
+            let any: AnyResourceId = format!("{}1234abcd", kind.prefix()).parse().unwrap();
+            assert_eq!(any.kind(), *kind);
+            assert_eq!(any.type_name(), kind.type_name());
         }
+        assert_eq!(ResourceKind::Subnet.prefix(), AwsSubnetId::PREFIX);
+        assert_eq!(ResourceKind::Subnet.type_name(), "AwsSubnetId");
 
-        #[cfg(feature = "serde")]
-        impl serde::Serialize for $type {
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-            where
-                S: serde::Serializer,
-            {
-                serializer.serialize_str(&self.to_string())
+        let prefixes: HashSet<_> = ResourceKind::all()
+            .iter()
+            .map(ResourceKind::prefix)
+            .collect();
+        assert_eq!(prefixes.len(), ResourceKind::all().len());
+        assert_eq!(ResourceKind::all().len(), AnyResourceId::PREFIXES.len());
+    }
+
+    #[test]
+    fn test_resource_id_vec() {
+        let mut subnets = ResourceIdVec::<AwsSubnetId>::new();
+        subnets.push_str("subnet-1234abcd").unwrap();
+        subnets.push_str("subnet-abcd1234").unwrap();
+        assert!(matches!(
+            subnets.push_str("vpc-1234abcd"),
+            Err(crate::Error::General(_))
+        ));
+        subnets.extend([AwsSubnetId::EXAMPLE]);
+
+        assert_eq!(subnets.len(), 3);
+        assert_eq!(subnets[1].to_string(), "subnet-abcd1234");
+        assert_eq!(subnets.last(), Some(&AwsSubnetId::EXAMPLE));
+        let ids: Vec<AwsSubnetId> = subnets.into_inner();
+        assert_eq!(ids[0].to_string(), "subnet-1234abcd");
+    }
+
+    #[test]
+    fn test_to_sql_literal() {
+        assert_eq!(ami("ami-12345678").to_sql_literal(), "'ami-12345678'");
+    }
+
+    #[test]
+    fn test_prefixes_unambiguous() {
+        let kinds = ResourceKind::all();
+        let mut overlapping = 0;
+        for a in kinds {
+            for b in kinds {
+                if a != b && b.prefix().starts_with(a.prefix()) {
+                    overlapping += 1;
+                    // The longest prefix wins, e.g. `tgw-attach-` over `tgw-`
+                    let id = format!("{}1234abcd", b.prefix());
+                    assert_eq!(id.parse::<AnyResourceId>().unwrap().kind(), *b, "{id}");
+                }
             }
         }
+        assert!(overlapping > 0);
 
-        #[cfg(feature = "serde")]
-        impl<'de> serde::Deserialize<'de> for $type {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-            where
-                D: serde::Deserializer<'de>,
-            {
-                let s = String::deserialize(deserializer)?;
-                $type::try_from(s).map_err(serde::de::Error::custom)
+        for kind in kinds {
+            for unique in ["1234abcd", "1234567890abcdef0"] {
+                let id = format!("{}{unique}", kind.prefix());
+                assert_eq!(id.parse::<AnyResourceId>().unwrap().kind(), *kind, "{id}");
             }
         }
-    };
-}
+    }
 
-fn short_type_name<T>() -> &'static str {
-    let name = std::any::type_name::<T>();
-    name.split("::").last().unwrap_or(name)
-}
+    #[test]
+    fn test_prefix() {
+        let attachment: AwsTransitGatewayAttachmentId = "tgw-attach-1234abcd".parse().unwrap();
+        assert_eq!(attachment.prefix(), "tgw-attach-");
+        assert_eq!(
+            AwsTransitGatewayConnectPeerId::EXAMPLE.prefix(),
+            "tgw-connect-peer-"
+        );
+        assert_eq!(AwsElasticBeanstalkEnvironmentId::EXAMPLE.prefix(), "e-");
+    }
 
-impl GeneralResourceError {
-    fn new(
-        target_type: &'static str,
-        input: impl Into<String>,
-        error_detail: GeneralResourceErrorDetail,
-    ) -> Self {
-        Self {
-            target_type,
-            input: input.into(),
-            error_detail,
+    #[test]
+    fn test_id_suffix() {
+        for input in ["ami-12345678", "tgw-attach-1234567890abcdef0"] {
+            let id: AnyResourceId = input.parse().unwrap();
+            let (prefix, suffix) = match &id {
+                AnyResourceId::Ami(id) => (id.prefix(), id.id_suffix()),
+                AnyResourceId::TransitGatewayAttachment(id) => (id.prefix(), id.id_suffix()),
+                _ => unreachable!(),
+            };
+            assert_eq!(format!("{prefix}{suffix}"), input);
         }
+        assert_eq!(ami("ami-12345678").id_suffix(), "12345678");
+        assert_eq!(AwsVpcId::EXAMPLE.id_suffix(), "1234567890abcdef0");
     }
-}
 
-impl_resource_id!(
-    AwsNetworkAclId,
-    "acl-",
-    "AWS Network ACL (Access Control List) ID"
-);
-impl_resource_id!(AwsAmiId, "ami-", "AWS AMI (Amazon Machine Image) ID");
-impl_resource_id!(AwsCustomerGatewayId, "cgw-", "AWS Customer Gateway ID");
-impl_resource_id!(AwsElasticIpId, "eipalloc-", "AWS Elastic IP ID");
-impl_resource_id!(
-    AwsEfsFileSystemId,
-    "fs-",
-    "AWS EFS (Elastic File System) ID"
-);
-impl_resource_id!(AwsEfsMountTargetId, "fsmt-", "AWS EFS Mount Target ID");
-impl_resource_id!(
-    AwsCloudFormationStackId,
-    "stack-",
-    "AWS CloudFormation Stack ID"
-);
-impl_resource_id!(
-    AwsElasticBeanstalkEnvironmentId,
-    "e-",
-    "AWS Elastic Beanstalk Environment ID"
-);
-impl_resource_id!(AwsInstanceId, "i-", "AWS EC2 Instance ID");
-impl_resource_id!(AwsInternetGatewayId, "igw-", "AWS Internet Gateway ID");
-impl_resource_id!(AwsKeyPairId, "key-", "AWS Key Pair ID");
-impl_resource_id!(AwsLoadBalancerId, "elbv2-", "AWS Elastic Load Balancer ID");
-impl_resource_id!(AwsNatGatewayId, "nat-", "AWS NAT Gateway ID");
-impl_resource_id!(AwsNetworkInterfaceId, "eni-", "AWS Network Interface ID");
-impl_resource_id!(AwsPlacementGroupId, "pg-", "AWS Placement Group ID");
-impl_resource_id!(AwsRdsInstanceId, "db-", "AWS RDS Instance ID");
-impl_resource_id!(AwsRedshiftClusterId, "redshift-", "AWS Redshift Cluster ID");
-impl_resource_id!(AwsRouteTableId, "rtb-", "AWS Route Table ID");
-impl_resource_id!(AwsSecurityGroupId, "sg-", "AWS Security Group ID");
-impl_resource_id!(AwsSnapshotId, "snap-", "AWS EBS Snapshot ID");
-impl_resource_id!(AwsSubnetId, "subnet-", "AWS VPC Subnet ID");
-impl_resource_id!(AwsTargetGroupId, "tg-", "AWS Target Group ID");
-impl_resource_id!(
-    AwsTransitGatewayAttachmentId,
-    "tgw-attach-",
-    "AWS Transit Gateway Attachment ID"
-);
-impl_resource_id!(AwsTransitGatewayId, "tgw-", "AWS Transit Gateway ID");
-impl_resource_id!(AwsVolumeId, "vol-", "AWS EBS Volume ID");
-impl_resource_id!(AwsVpcId, "vpc-", "AWS VPC (Virtual Private Cloud) ID");
-impl_resource_id!(AwsVpnConnectionId, "vpn-", "AWS VPN Connection ID");
-impl_resource_id!(AwsVpnGatewayId, "vgw-", "AWS VPN Gateway ID");
+    #[test]
+    fn test_is_short_long() {
+        let short = ami("ami-12345678");
+        assert!(short.is_short());
+        assert!(!short.is_long());
+        let long = ami("ami-1a2b3c4d5e6f7j8h9");
+        assert!(long.is_long());
+        assert!(!long.is_short());
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_whitespace() {
+        let detail = |s: &str| match AwsAmiId::try_from(s) {
+            Err(crate::Error::General(e)) => e.error_detail.kind(),
+            other => panic!("{other:?}"),
+        };
+        assert_eq!(detail(" ami-12345678"), "surrounding_whitespace");
+        assert_eq!(detail("ami-12345678\n"), "surrounding_whitespace");
+        assert_eq!(detail("ami- 12345678"), "id_length");
+        assert_eq!(detail("ami- 1234567"), "non_ascii_alphanumeric");
+        assert_eq!(detail("ami-1234 678"), "non_ascii_alphanumeric");
+        assert_eq!(
+            AwsAmiId::try_from(" ami-12345678").unwrap_err().to_string(),
+            "failed to initialize AwsAmiId from \" ami-12345678\": the id has leading or trailing whitespace"
+        );
+    }
 
-    fn ami(s: &str) -> AwsAmiId {
-        AwsAmiId::try_from(s).unwrap()
+    #[test]
+    fn test_analyze() {
+        let analysis = analyze("tgw-attach-1234567890abcdef0");
+        assert_eq!(analysis.prefix, Some(0..11));
+        assert_eq!(analysis.unique, Some(11..28));
+        assert!(analysis.is_valid());
+
+        let analysis = analyze("vpc-12é4abc");
+        assert_eq!(analysis.unique, Some(4..12));
+        assert!(matches!(
+            analysis.error,
+            Some((r, GeneralResourceErrorDetail::NonAsciiAlphanumeric)) if r == (6..8)
+        ));
+
+        // Both the length and a character are wrong, the length is reported
+        // first, same as parsing does
+        let input = "ami-12$";
+        assert!(matches!(
+            analyze(input).error,
+            Some((r, GeneralResourceErrorDetail::IdLength(3))) if r == (4..7)
+        ));
+        let Err(crate::Error::General(err)) = AnyResourceId::try_from(input) else {
+            panic!("{input} is parsed");
+        };
+        assert!(matches!(
+            err.error_detail,
+            GeneralResourceErrorDetail::IdLength(3)
+        ));
+
+        assert!(matches!(
+            analyze("vpc-1234").error,
+            Some((r, GeneralResourceErrorDetail::IdLength(4))) if r == (4..8)
+        ));
+        let analysis = analyze("foo-1234abcd");
+        assert_eq!(analysis.prefix, Some(0..4));
+        assert!(matches!(
+            analysis.error,
+            Some((r, GeneralResourceErrorDetail::UnknownPrefix)) if r == (0..4)
+        ));
+        assert!(matches!(
+            analyze("1234abcd").error,
+            Some((r, GeneralResourceErrorDetail::InvalidPrefix)) if r == (0..8)
+        ));
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_eq() {
-        assert_eq!(ami("ami-12345678"), ami("ami-12345678"));
-        assert_ne!(ami("ami-12345678"), ami("ami-abcdefgh"));
+    fn test_group_by_kind() {
+        let ids: Vec<AnyResourceId> = [
+            "vpc-1234abcd",
+            "subnet-1234abcd",
+            "vpc-abcd1234",
+            "i-1234abcd",
+            "subnet-abcd1234",
+            "vpc-1a2b3c4d5e6f7j8h9",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        let groups = group_by_kind(&ids);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&ResourceKind::Vpc].len(), 3);
+        assert_eq!(groups[&ResourceKind::Subnet].len(), 2);
+        assert_eq!(groups[&ResourceKind::Instance], [ids[3]]);
+        assert_eq!(groups[&ResourceKind::Vpc][1].to_string(), "vpc-abcd1234");
     }
 
     #[test]
-    fn test_fmt_display() {
-        assert_eq!(format!("{}", ami("ami-12345678")), "ami-12345678");
+    fn test_prefixes_end_with_hyphen() {
+        for prefix in AnyResourceId::PREFIXES {
+            assert!(prefix.ends_with('-'), "{prefix}");
+        }
     }
 
     #[test]
-    fn test_fmt_debug() {
+    fn test_any_resource_id_unknown_prefix() {
+        let err = AnyResourceId::try_from("???-1").unwrap_err().to_string();
+        assert!(
+            err.starts_with(
+                "failed to initialize AnyResourceId from \"???-1\": unknown prefix, expected one of: acl-, ami-, "
+            ),
+            "{err}"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_any_resource_id_deserialize() {
         assert_eq!(
-            format!("{:?}", ami("ami-12345678")),
-            r#"AwsAmiId("ami-12345678")"#
+            serde_json::from_str::<AnyResourceId>("\"subnet-1234abcd\"").unwrap(),
+            AnyResourceId::Subnet(AwsSubnetId::try_from("subnet-1234abcd").unwrap())
         );
+        let err = serde_json::from_str::<AnyResourceId>("\"???-1\"").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("unknown prefix, expected one of: acl-"));
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn test_into_string() {
-        let s: String = ami("ami-12345678").into();
-        assert_eq!(s, "ami-12345678");
+    fn test_any_resource_id_serialize() {
+        let id = AnyResourceId::try_from("subnet-1234abcd").unwrap();
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"subnet-1234abcd\"");
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn test_tryfrom_str() {
-        assert!(AwsAmiId::try_from("ami-12345678").is_ok());
+    fn test_any_resource_id_serde_roundtrip() {
+        for prefix in AnyResourceId::PREFIXES {
+            for unique in ["1234abcd", "1a2b3c4d5e6f7j8h9"] {
+                let input = format!("{prefix}{unique}");
+                let id = AnyResourceId::try_from(input.as_str()).unwrap();
+                assert_eq!(id.to_string(), input);
+                let json = serde_json::to_string(&id).unwrap();
+                assert_eq!(json, format!("\"{input}\""));
+                assert_eq!(serde_json::from_str::<AnyResourceId>(&json).unwrap(), id);
+            }
+        }
     }
 
     #[test]
-    fn test_tryfrom_string() {
-        assert!(AwsAmiId::try_from("ami-12345678".to_string()).is_ok());
+    fn test_id_pattern() {
+        let instance = |s| AwsInstanceId::try_from(s).unwrap();
+        let pattern = IdPattern::try_from("i-0abc*").unwrap();
+        assert!(pattern.matches(&instance("i-0abcdef0123456789")));
+        assert!(!pattern.matches(&instance("i-1abcdef0123456789")));
+        assert_eq!(pattern.to_string(), "i-0abc*");
+
+        let any = IdPattern::try_from("i-*").unwrap();
+        assert!(any.matches(&instance("i-1234abcd")));
+        assert!(!any.matches(&AwsVpcId::try_from("vpc-1234abcd").unwrap()));
+
+        let exact = IdPattern::try_from("i-1234abcd").unwrap();
+        assert!(exact.matches(&instance("i-1234abcd")));
+        assert!(!exact.matches(&instance("i-1234abce")));
+        assert!(!exact.matches(&instance("i-1234abcd123456789")));
+
+        // Patterns ending within the prefix
+        assert!(IdPattern::try_from("i*")
+            .unwrap()
+            .matches(&instance("i-1234abcd")));
+        assert!(!IdPattern::try_from("i-")
+            .unwrap()
+            .matches(&instance("i-1234abcd")));
+        assert!(!IdPattern::try_from("vp*")
+            .unwrap()
+            .matches(&instance("i-1234abcd")));
+        assert!(IdPattern::try_from("vp*")
+            .unwrap()
+            .matches(&AwsVpcId::try_from("vpc-1234abcd").unwrap()));
     }
 
     #[test]
-    fn test_tryfrom_refstring() {
-        assert!(AwsAmiId::try_from(&"ami-12345678".to_string()).is_ok());
+    fn test_id_pattern_invalid() {
+        for input in ["", "*", "i-*abc", "i-**", "i-0 *"] {
+            match IdPattern::try_from(input) {
+                Err(crate::Error::General(e)) => assert!(
+                    matches!(e.error_detail, GeneralResourceErrorDetail::InvalidPattern),
+                    "{input}"
+                ),
+                other => panic!("{input}: expected a pattern error, got {other:?}"),
+            }
+        }
     }
 
     #[test]
-    fn test_fromstr() {
-        assert!("ami-12345678".parse::<AwsAmiId>().is_ok(),);
-        assert!("ami-12345678".to_string().parse::<AwsAmiId>().is_ok(),);
+    fn test_id_or_wildcard() {
+        let id: IdOrWildcard<AwsAmiId> = "ami-12345678".parse().unwrap();
+        assert_eq!(id, IdOrWildcard::Id(ami("ami-12345678")));
+        assert!(id.matches(&ami("ami-12345678")));
+        assert!(!id.matches(&ami("ami-87654321")));
+        let any: IdOrWildcard<AwsAmiId> = "*".parse().unwrap();
+        assert_eq!(any, IdOrWildcard::Wildcard);
+        assert!(any.matches(&ami("ami-87654321")));
+        assert_eq!(any.to_string(), "*");
+        assert!(IdOrWildcard::<AwsAmiId>::try_from("ami-*").is_err());
     }
 
     #[cfg(feature = "serde")]
     #[test]
-    fn test_serialize() {
+    fn test_id_or_wildcard_serde() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Statement {
+            #[serde(default)]
+            image: Option<IdOrWildcard<AwsAmiId>>,
+        }
+
+        let parse = |json| serde_json::from_str::<Statement>(json).map(|s| s.image);
         assert_eq!(
-            serde_json::to_string(&ami("ami-12345678")).unwrap(),
-            "\"ami-12345678\""
+            parse(r#"{"image": "*"}"#).unwrap(),
+            Some(IdOrWildcard::Wildcard)
+        );
+        assert_eq!(
+            parse(r#"{"image": "ami-12345678"}"#).unwrap(),
+            Some(IdOrWildcard::Id(ami("ami-12345678")))
+        );
+        assert_eq!(parse(r#"{"image": null}"#).unwrap(), None);
+        assert_eq!(parse("{}").unwrap(), None);
+        assert!(parse(r#"{"image": "any"}"#).is_err());
+        assert_eq!(
+            serde_json::to_string(&IdOrWildcard::<AwsAmiId>::Wildcard).unwrap(),
+            "\"*\""
+        );
+    }
+
+    #[test]
+    fn test_raw_from_parts() {
+        let id = RawResourceId::from_parts("lt-", "1234abcd").unwrap();
+        assert_eq!(id.prefix(), "lt-");
+        assert_eq!(id.to_string(), "lt-1234abcd");
+        assert_eq!(
+            RawResourceId::from_parts("tgw-rtb-", "1a2b3c4d5e6f7j8h9")
+                .unwrap()
+                .to_string(),
+            "tgw-rtb-1a2b3c4d5e6f7j8h9"
+        );
+        assert_eq!(
+            RawResourceId::from_parts("lt", "1234abcd")
+                .unwrap_err()
+                .to_string(),
+            "failed to initialize RawResourceId from \"lt1234abcd\": the prefix must be lowercase ascii alphanumeric words each followed by \"-\", up to 17 characters long"
+        );
+        for prefix in [
+            "-",
+            "!!-",
+            "lt--",
+            "-lt-",
+            "LT-",
+            "l t-",
+            "lé-",
+            "tgw-connect-peers-",
+        ] {
+            assert!(
+                matches!(
+                    RawResourceId::from_parts(prefix, "1234abcd"),
+                    Err(crate::Error::General(GeneralResourceError {
+                        error_detail: GeneralResourceErrorDetail::InvalidPrefix,
+                        ..
+                    }))
+                ),
+                "{prefix}"
+            );
+        }
+        let id = RawResourceId::from_parts("tgw-connect-peer-", "1234abcd").unwrap();
+        assert_eq!(id.to_string().parse::<RawResourceId>().unwrap(), id);
+        assert!(RawResourceId::from_parts("lt-", "1234").is_err());
+    }
+
+    #[test]
+    fn test_raw_tryfrom_str() {
+        let id = RawResourceId::try_from("tgw-rtb-1234abcd").unwrap();
+        assert_eq!(id.prefix(), "tgw-rtb-");
+        assert_eq!(
+            id,
+            RawResourceId::from_parts("tgw-rtb-", "1234abcd").unwrap()
         );
+        assert!(RawResourceId::try_from("1234abcd").is_err());
+        assert!(RawResourceId::try_from("lt-1234abc!").is_err());
     }
 
     #[cfg(feature = "serde")]
     #[test]
-    fn test_deserialize() {
+    fn test_deserialize_wrong_type_in_struct() {
+        #[derive(Debug, serde::Deserialize)]
+        #[allow(dead_code)]
+        struct Image {
+            ami: AwsAmiId,
+        }
+
+        let err = serde_json::from_str::<Image>(r#"{ "ami": "vol-12345678" }"#)
+            .unwrap_err()
+            .to_string();
         assert_eq!(
-            serde_json::from_str::<AwsAmiId>("\"ami-12345678\"").unwrap(),
-            ami("ami-12345678"),
+            err,
+            "failed to initialize AwsAmiId from \"vol-12345678\": incorrect prefix, expected \"ami-\" at line 1 column 23"
+        );
+
+        let err = serde_json::from_str::<Image>(r#"{ "ami": 1 }"#)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "invalid type: integer `1`, expected a string AWS AMI (Amazon Machine Image) ID starting with \"ami-\" at line 1 column 10"
         );
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_yaml_alias() {
+        #[derive(serde::Deserialize)]
+        struct Images {
+            base: AwsAmiId,
+            current: AwsAmiId,
+        }
+
+        let images: Images =
+            serde_yaml::from_str("base: &ami ami-12345678\ncurrent: *ami\n").unwrap();
+        assert_eq!(images.base, ami("ami-12345678"));
+        assert_eq!(images.current, images.base);
+    }
+
     #[test]
     fn test_wrong_prefix() {
         let result = AwsAmiId::try_from("amx-12345678");
@@ -375,6 +2691,120 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_near_miss_prefixes() {
+        use crate::Error;
+
+        macro_rules! assert_near_miss {
+            ($($type:ident, $good:literal, $bad:literal;)*) => {$(
+                assert_eq!($type::PREFIX, $good);
+                let input = concat!($bad, "1234abcd");
+                match $type::try_from(input) {
+                    Err(Error::General(e)) => assert!(
+                        matches!(e.error_detail, GeneralResourceErrorDetail::WrongPrefix($good)),
+                        "{input}: {e}"
+                    ),
+                    other => panic!("{input}: expected a wrong prefix error, got {other:?}"),
+                }
+            )*};
+        }
+
+        assert_near_miss!(
+            AwsNetworkAclId, "acl-", "acm-";
+            AwsAmiId, "ami-", "amj-";
+            AwsCustomerGatewayId, "cgw-", "cgv-";
+            AwsElasticIpId, "eipalloc-", "eipallod-";
+            AwsElasticIpAssociationId, "eipassoc-", "eipassod-";
+            AwsEfsFileSystemId, "fs-", "fd-";
+            AwsEfsMountTargetId, "fsmt-", "fsnt-";
+            AwsCloudFormationStackId, "stack-", "stacj-";
+            AwsElasticBeanstalkEnvironmentId, "e-", "f-";
+            AwsInstanceId, "i-", "j-";
+            AwsInternetGatewayId, "igw-", "igx-";
+            AwsKeyPairId, "key-", "kez-";
+            AwsLoadBalancerId, "elbv2-", "elbv3-";
+            AwsNatGatewayId, "nat-", "nay-";
+            AwsNetworkInterfaceId, "eni-", "emi-";
+            AwsPlacementGroupId, "pg-", "ph-";
+            AwsRdsInstanceId, "db-", "dc-";
+            AwsRedshiftClusterId, "redshift-", "redshifr-";
+            AwsRouteTableId, "rtb-", "rtc-";
+            AwsSecurityGroupId, "sg-", "sh-";
+            AwsSnapshotId, "snap-", "smap-";
+            AwsSubnetId, "subnet-", "subnat-";
+            AwsTargetGroupId, "tg-", "th-";
+            AwsTransitGatewayAttachmentId, "tgw-attach-", "tgw-attacb-";
+            AwsTransitGatewayConnectPeerId, "tgw-connect-peer-", "tgw-connect-peeq-";
+            AwsTransitGatewayMulticastDomainId, "tgw-mcast-domain-", "tgw-mcast-domaim-";
+            AwsTransitGatewayRouteTableId, "tgw-rtb-", "tgw-rtc-";
+            AwsTransitGatewayId, "tgw-", "tgx-";
+            AwsVolumeId, "vol-", "vok-";
+            AwsVpcId, "vpc-", "vpd-";
+            AwsVpnConnectionId, "vpn-", "vpm-";
+            AwsVpnGatewayId, "vgw-", "vgv-";
+        );
+    }
+
+    #[test]
+    fn test_elastic_ip_allocation_vs_association() {
+        assert!(AwsElasticIpId::try_from("eipassoc-1234abcd").is_err());
+        assert!(AwsElasticIpAssociationId::try_from("eipalloc-1234abcd").is_err());
+        assert_eq!(
+            AnyResourceId::try_from("eipassoc-1234abcd").unwrap(),
+            AnyResourceId::ElasticIpAssociation(
+                AwsElasticIpAssociationId::try_from("eipassoc-1234abcd").unwrap()
+            )
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_error() {
+        let err = AwsAmiId::try_from("amx-12345678").unwrap_err();
+        assert_eq!(
+            serde_json::to_value(&err).unwrap(),
+            serde_json::json!({
+                "kind": "wrong_prefix",
+                "input": "amx-12345678",
+                "message": "failed to initialize AwsAmiId from \"amx-12345678\": incorrect prefix, expected \"ami-\"",
+            })
+        );
+    }
+
+    #[test]
+    fn test_bare_prefix() {
+        fn assert_empty_unique(result: Result<impl fmt::Debug, crate::Error>, input: &str) {
+            match result {
+                Err(crate::Error::General(e)) => assert!(
+                    matches!(e.error_detail, GeneralResourceErrorDetail::IdLength(0)),
+                    "{input}: {e}"
+                ),
+                other => panic!("{input}: expected an empty unique part error, got {other:?}"),
+            }
+        }
+
+        assert_empty_unique(
+            AwsTransitGatewayAttachmentId::try_from("tgw-attach-"),
+            "tgw-attach-",
+        );
+        assert_empty_unique(
+            AwsTransitGatewayConnectPeerId::try_from("tgw-connect-peer-"),
+            "tgw-connect-peer-",
+        );
+        assert_empty_unique(AwsElasticIpId::try_from("eipalloc-"), "eipalloc-");
+        for prefix in AnyResourceId::PREFIXES {
+            assert_empty_unique(AnyResourceId::try_from(*prefix), prefix);
+        }
+        // The shorter prefix type sees `attach-` as a (too short) unique part
+        match AwsTransitGatewayId::try_from("tgw-attach-") {
+            Err(crate::Error::General(e)) => assert!(
+                matches!(e.error_detail, GeneralResourceErrorDetail::IdLength(7)),
+                "{e}"
+            ),
+            other => panic!("expected a length error, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_error_wrong_length() {
         let result = AwsAmiId::try_from("ami-1234567");
@@ -392,6 +2822,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_long_non_alphanumeric() {
+        let input = format!("ami-{}", "!".repeat(10_000));
+        match AwsAmiId::try_from(input.as_str()) {
+            Err(crate::Error::General(e)) => {
+                assert!(matches!(
+                    e.error_detail,
+                    GeneralResourceErrorDetail::IdLength(10_000)
+                ))
+            }
+            other => panic!("expected a length error, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_error_non_alphanumeric() {
         let result = AwsAmiId::try_from("ami-1234567!");
@@ -426,6 +2870,12 @@ mod tests {
                 .to_string(),
             "eipalloc-1234abcd"
         );
+        assert_eq!(
+            AwsElasticIpAssociationId::try_from("eipassoc-1234abcd")
+                .unwrap()
+                .to_string(),
+            "eipassoc-1234abcd"
+        );
         assert_eq!(
             AwsEfsFileSystemId::try_from("fs-1234abcd")
                 .unwrap()
@@ -536,6 +2986,24 @@ mod tests {
                 .to_string(),
             "tgw-attach-1234abcd"
         );
+        assert_eq!(
+            AwsTransitGatewayConnectPeerId::try_from("tgw-connect-peer-1234abcd")
+                .unwrap()
+                .to_string(),
+            "tgw-connect-peer-1234abcd"
+        );
+        assert_eq!(
+            AwsTransitGatewayMulticastDomainId::try_from("tgw-mcast-domain-1234abcd")
+                .unwrap()
+                .to_string(),
+            "tgw-mcast-domain-1234abcd"
+        );
+        assert_eq!(
+            AwsTransitGatewayRouteTableId::try_from("tgw-rtb-1234abcd")
+                .unwrap()
+                .to_string(),
+            "tgw-rtb-1234abcd"
+        );
         assert_eq!(
             AwsTransitGatewayId::try_from("tgw-1234abcd")
                 .unwrap()
@@ -590,6 +3058,12 @@ mod tests {
                 .to_string(),
             "eipalloc-1a2b3c4d5e6f7j8h9"
         );
+        assert_eq!(
+            AwsElasticIpAssociationId::try_from("eipassoc-1a2b3c4d5e6f7j8h9")
+                .unwrap()
+                .to_string(),
+            "eipassoc-1a2b3c4d5e6f7j8h9"
+        );
         assert_eq!(
             AwsEfsFileSystemId::try_from("fs-1a2b3c4d5e6f7j8h9")
                 .unwrap()
@@ -704,6 +3178,24 @@ mod tests {
                 .to_string(),
             "tgw-attach-1a2b3c4d5e6f7j8h9"
         );
+        assert_eq!(
+            AwsTransitGatewayConnectPeerId::try_from("tgw-connect-peer-1a2b3c4d5e6f7j8h9")
+                .unwrap()
+                .to_string(),
+            "tgw-connect-peer-1a2b3c4d5e6f7j8h9"
+        );
+        assert_eq!(
+            AwsTransitGatewayMulticastDomainId::try_from("tgw-mcast-domain-1a2b3c4d5e6f7j8h9")
+                .unwrap()
+                .to_string(),
+            "tgw-mcast-domain-1a2b3c4d5e6f7j8h9"
+        );
+        assert_eq!(
+            AwsTransitGatewayRouteTableId::try_from("tgw-rtb-1a2b3c4d5e6f7j8h9")
+                .unwrap()
+                .to_string(),
+            "tgw-rtb-1a2b3c4d5e6f7j8h9"
+        );
         assert_eq!(
             AwsTransitGatewayId::try_from("tgw-1a2b3c4d5e6f7j8h9")
                 .unwrap()
@@ -737,6 +3229,57 @@ mod tests {
     }
 }
 
+/// Locks the consistency of `Hash` with `Eq` for ids built in different ways,
+/// so storage refactors can't break hash-based collections
+#[cfg(test)]
+mod hash_stability {
+    use super::*;
+    use std::collections::HashSet;
+
+    macro_rules! assert_hash_stable {
+        ($($type:ident),* $(,)?) => {$(
+            for unique in ["1234abcd", "1234567890abcdef0"] {
+                let input = format!("{}{unique}", $type::PREFIX);
+                let mut set = HashSet::new();
+                set.insert($type::try_from(input.as_str()).unwrap());
+                set.insert(input.parse::<$type>().unwrap());
+                set.insert($type::try_from(input.clone()).unwrap());
+                set.insert(
+                    AwsVpcId::try_from(format!("vpc-{unique}"))
+                        .unwrap()
+                        .reinterpret_unique_as::<$type>(),
+                );
+                assert_eq!(set.len(), 1, "{input}");
+
+                let mut any = HashSet::new();
+                any.insert(AnyResourceId::try_from(input.as_str()).unwrap());
+                any.insert(AnyResourceId::try_from(input.clone()).unwrap());
+                assert_eq!(any.len(), 1, "{input}");
+            }
+        )*};
+    }
+
+    #[test]
+    fn test_hash_stability() {
+        assert_hash_stable!(
+            AwsAmiId,
+            AwsInstanceId,
+            AwsSubnetId,
+            AwsTransitGatewayAttachmentId,
+            AwsTransitGatewayConnectPeerId,
+            AwsVpcId,
+        );
+    }
+
+    #[test]
+    fn test_example_hash_stability() {
+        let mut set = HashSet::new();
+        set.insert(AwsAmiId::EXAMPLE);
+        set.insert(AwsAmiId::try_from("ami-1234567890abcdef0").unwrap());
+        assert_eq!(set.len(), 1);
+    }
+}
+
 #[cfg(feature = "sqlx-postgres")]
 #[cfg(test)]
 mod sqlx_tests {
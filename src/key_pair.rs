@@ -0,0 +1,163 @@
+//! # EC2 Key Pair Name
+//!
+//! EC2 key pairs have both an id ([`AwsKeyPairId`], e.g. `key-1234abcd`) and
+//! a user-chosen name, e.g. `deploy`. Most APIs and tools, like `KeyName` in
+//! `RunInstances` or `ssh -i`, reference key pairs by the name, so the two are
+//! easy to mix up. A name may even look like an id, so a string that parses
+//! as both should be treated according to where it came from.
+//!
+//! [`AwsKeyPairId`]: crate::AwsKeyPairId
+use alloc::string::String;
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+/// Error encountered when parsing an EC2 key pair name
+#[derive(Debug, thiserror::Error)]
+#[error("invalid key pair name \"{0}\", expected 1 to 255 printable ASCII characters without leading or trailing spaces")]
+pub struct KeyPairNameError(String);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for KeyPairNameError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serialize_error(
+            serializer,
+            "KeyPairNameError",
+            "invalid_key_pair_name",
+            Some(self.0.as_str()),
+            self,
+        )
+    }
+}
+
+/// EC2 Key Pair Name, see the [module docs](self) on how it differs from the
+/// key pair id
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ec2KeyPairName(String);
+
+impl Ec2KeyPairName {
+    /// The maximum length of a name
+    pub const MAX_LEN: usize = 255;
+
+    /// The name as a string slice
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for Ec2KeyPairName {
+    type Error = crate::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let valid = (1..=Self::MAX_LEN).contains(&s.len())
+            && s.bytes().all(|c| (b' '..=b'~').contains(&c))
+            && !s.starts_with(' ')
+            && !s.ends_with(' ');
+        if !valid {
+            return Err(KeyPairNameError(s.into()).into());
+        }
+        Ok(Self(s.into()))
+    }
+}
+
+impl TryFrom<String> for Ec2KeyPairName {
+    type Error = crate::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl TryFrom<&String> for Ec2KeyPairName {
+    type Error = crate::Error;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl FromStr for Ec2KeyPairName {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl AsRef<str> for Ec2KeyPairName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Ec2KeyPairName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<Ec2KeyPairName> for String {
+    fn from(value: Ec2KeyPairName) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Ec2KeyPairName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Ec2KeyPairName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <alloc::borrow::Cow<'_, str> as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(&*s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AwsKeyPairId, Error};
+
+    #[test]
+    fn test_parse() {
+        let name: Ec2KeyPairName = "deploy key (prod)".parse().unwrap();
+        assert_eq!(name.as_str(), "deploy key (prod)");
+        assert_eq!(name.to_string(), "deploy key (prod)");
+        assert!(Ec2KeyPairName::try_from("x".repeat(255)).is_ok());
+    }
+
+    #[test]
+    fn test_invalid() {
+        for input in [
+            String::new(),
+            "x".repeat(256),
+            " deploy".into(),
+            "deploy ".into(),
+            "tab\tkey".into(),
+            "ключ".into(),
+        ] {
+            assert!(
+                matches!(Ec2KeyPairName::try_from(&input), Err(Error::KeyPairName(_))),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_name_is_not_an_id() {
+        assert!(Ec2KeyPairName::try_from("deploy").is_ok());
+        assert!(AwsKeyPairId::try_from("deploy").is_err());
+    }
+}
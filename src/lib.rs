@@ -1,15 +1,35 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![forbid(unsafe_code)]
 #![warn(clippy::all, missing_docs, nonstandard_style, future_incompatible)]
 
+extern crate alloc;
+
+pub mod account;
+pub mod arn;
+#[cfg(feature = "serde")]
+pub mod aws_describe;
 pub mod general;
+pub mod key_pair;
+pub mod prelude;
 pub mod region;
+pub mod scoped;
 
+pub use account::*;
+pub use arn::*;
+use core::str::FromStr;
 pub use general::*;
+pub use key_pair::*;
 pub use region::*;
+pub use scoped::*;
+#[cfg(feature = "std")]
+use std::io::BufRead;
 
 /// AWS resource ID parsing or validating error
+///
+/// New variants may be added in minor releases.
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum Error {
     /// Parsing AWS resource ID in the general format
     #[error(transparent)]
@@ -17,4 +37,314 @@ pub enum Error {
     /// Parsing AWS region ID
     #[error(transparent)]
     Region(#[from] RegionError),
+    /// A region qualified with a partition it doesn't belong to
+    #[error(transparent)]
+    PartitionMismatch(#[from] PartitionMismatchError),
+    /// Parsing a region scoped ID
+    #[error(transparent)]
+    Scoped(#[from] ScopedIdError),
+    /// Parsing an EBS snapshot reference
+    #[error(transparent)]
+    SnapshotRef(#[from] SnapshotRefError),
+    /// Parsing AWS account ID
+    #[error(transparent)]
+    Account(#[from] AccountIdError),
+    /// Parsing a region and account pair
+    #[error(transparent)]
+    RegionAccount(#[from] RegionAccountError),
+    /// Parsing a shared resource reference
+    #[error(transparent)]
+    SharedRef(#[from] SharedRefError),
+    /// Parsing an ARN
+    #[error(transparent)]
+    Arn(#[from] ArnError),
+    /// Parsing a `service:resource-type` spec
+    #[error(transparent)]
+    ResourceSpec(#[from] ResourceSpecError),
+    /// An ARN doesn't satisfy a constraint
+    #[error(transparent)]
+    ArnConstraint(#[from] ArnConstraintError),
+    /// Parsing an EC2 key pair name
+    #[error(transparent)]
+    KeyPairName(#[from] KeyPairNameError),
+    /// Reading the input failed
+    #[cfg(feature = "std")]
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// Reading an environment variable failed, e.g. it isn't set
+    #[cfg(feature = "std")]
+    #[error("failed to read environment variable {var}: {source}")]
+    EnvVar {
+        /// Name of the variable
+        var: String,
+        /// The underlying error
+        source: std::env::VarError,
+    },
+}
+
+/// Serializes into a structured `{ "kind", "input", "message" }` object, e.g.
+/// for API error responses. The input is `null` for IO and environment
+/// variable errors.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::General(e) => e.serialize(serializer),
+            Self::Region(e) => e.serialize(serializer),
+            Self::PartitionMismatch(e) => e.serialize(serializer),
+            Self::Scoped(e) => e.serialize(serializer),
+            Self::SnapshotRef(e) => e.serialize(serializer),
+            Self::Account(e) => e.serialize(serializer),
+            Self::RegionAccount(e) => e.serialize(serializer),
+            Self::SharedRef(e) => e.serialize(serializer),
+            Self::Arn(e) => e.serialize(serializer),
+            Self::ResourceSpec(e) => e.serialize(serializer),
+            Self::ArnConstraint(e) => e.serialize(serializer),
+            Self::KeyPairName(e) => e.serialize(serializer),
+            #[cfg(feature = "std")]
+            Self::Io(e) => serialize_error(serializer, "Error", "io", None, e),
+            #[cfg(feature = "std")]
+            Self::EnvVar { .. } => serialize_error(serializer, "Error", "env_var", None, self),
+        }
+    }
+}
+
+/// Serializes an error into the `{ "kind", "input", "message" }` object
+/// shared by all the errors of the crate
+#[cfg(feature = "serde")]
+fn serialize_error<S>(
+    serializer: S,
+    name: &'static str,
+    kind: &'static str,
+    input: Option<&str>,
+    message: &dyn core::fmt::Display,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use alloc::string::ToString;
+    use serde::ser::SerializeStruct;
+
+    let mut state = serializer.serialize_struct(name, 3)?;
+    state.serialize_field("kind", kind)?;
+    state.serialize_field("input", &input)?;
+    state.serialize_field("message", &message.to_string())?;
+    state.end()
+}
+
+/// Options for lenient parsing of ids, all disabled (strict) by default
+///
+/// ```
+/// use aws_resource_id::{AwsAmiId, ParseOptions};
+///
+/// let options = ParseOptions::default().strip_angle_brackets(true);
+/// let ami: AwsAmiId = options.parse("<ami-12345678>").unwrap();
+/// assert_eq!(ami.to_string(), "ami-12345678");
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    strip_angle_brackets: bool,
+}
+
+impl ParseOptions {
+    /// Removes a single surrounding `<...>` pair, e.g. left by a templating
+    /// engine
+    #[must_use]
+    pub fn strip_angle_brackets(mut self, enabled: bool) -> Self {
+        self.strip_angle_brackets = enabled;
+        self
+    }
+
+    /// Parses an id applying the options
+    pub fn parse<T>(&self, s: &str) -> Result<T, Error>
+    where
+        T: FromStr<Err = Error>,
+    {
+        let mut s = s;
+        if self.strip_angle_brackets {
+            s = s
+                .strip_prefix('<')
+                .and_then(|s| s.strip_suffix('>'))
+                .unwrap_or(s);
+        }
+        s.parse()
+    }
+}
+
+/// A region or a general format id (de)serialized compactly by binary
+/// formats: regions as a stable one byte index, ids as their unique part bytes,
+/// as the prefix is implied by the type
+///
+/// Human-readable formats, like JSON, still get the string form. The compact
+/// form is opt-in, as it can't read the strings stored by the plain types.
+///
+/// ```
+/// use aws_resource_id::{AwsRegionId, Compact};
+///
+/// let mut cbor = Vec::new();
+/// ciborium::into_writer(&Compact(AwsRegionId::EuWest1), &mut cbor).unwrap();
+/// assert_eq!(cbor.len(), 1);
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Compact<T>(pub T);
+
+/// Normalizes deserialized input: with the `serde-lenient` feature ids are
+/// trimmed and lowercased before parsing, otherwise they're kept as is
+///
+/// Only uppercase input gets copied, so deserializing normalized ids doesn't
+/// allocate in either mode.
+#[cfg(feature = "serde")]
+fn lenient_input(s: &str) -> alloc::borrow::Cow<'_, str> {
+    #[cfg(feature = "serde-lenient")]
+    let s = s.trim();
+    #[cfg(feature = "serde-lenient")]
+    if s.bytes().any(|b| b.is_ascii_uppercase()) {
+        return s.to_ascii_lowercase().into();
+    }
+    s.into()
+}
+
+/// Loads ids from a reader, one per line, e.g. from an inventory dump
+///
+/// Lines are trimmed, blank lines and lines starting with `#` are skipped.
+#[cfg(feature = "std")]
+pub fn load_ids_from_reader<R, T>(reader: R) -> Result<Vec<T>, Error>
+where
+    R: BufRead,
+    T: FromStr<Err = Error>,
+{
+    let mut ids = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        ids.push(line.parse()?);
+    }
+    Ok(ids)
+}
+
+/// Sorts ids and removes duplicates, e.g. after ingesting them from several
+/// sources
+///
+/// General format ids of a type are ordered with the short (8 characters) ids
+/// first, then by the unique part bytes. Regions are ordered by their codes.
+pub fn sort_dedup<T: Ord>(ids: &mut alloc::vec::Vec<T>) {
+    ids.sort_unstable();
+    ids.dedup();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use std::io::Cursor;
+
+    #[test]
+    fn test_core_error() {
+        fn source(e: &dyn core::error::Error) -> Option<&dyn core::error::Error> {
+            e.source()
+        }
+
+        let err = AwsAmiId::try_from("vpc-1234abcd").unwrap_err();
+        let Error::General(general) = &err else {
+            panic!("{err:?}");
+        };
+        assert!(source(general).is_none());
+        assert_eq!(
+            general.to_string(),
+            "failed to initialize AwsAmiId from \"vpc-1234abcd\": incorrect prefix, expected \"ami-\""
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_load_ids_from_reader() {
+        let input = "# instances\n\ni-1234abcd\n  i-1a2b3c4d5e6f7j8h9  \n\n# the end\n";
+        let ids: Vec<AwsInstanceId> = load_ids_from_reader(Cursor::new(input)).unwrap();
+        assert_eq!(
+            ids,
+            [
+                "i-1234abcd".parse::<AwsInstanceId>().unwrap(),
+                "i-1a2b3c4d5e6f7j8h9".parse().unwrap(),
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde-lenient")]
+    #[test]
+    fn test_deserialize_lenient() {
+        let ami: AwsAmiId = serde_json::from_str("\" AMI-1234ABCD \"").unwrap();
+        assert_eq!(ami.to_string(), "ami-1234abcd");
+        let id: AnyResourceId = serde_json::from_str("\" AMI-1234ABCD \"").unwrap();
+        assert_eq!(id.to_string(), "ami-1234abcd");
+        let region: AwsRegionId = serde_json::from_str("\"EU-WEST-1 \"").unwrap();
+        assert_eq!(region, AwsRegionId::EuWest1);
+    }
+
+    #[cfg(all(feature = "serde", not(feature = "serde-lenient")))]
+    #[test]
+    fn test_deserialize_strict() {
+        assert!(serde_json::from_str::<AwsAmiId>("\" AMI-1234ABCD \"").is_err());
+        assert!(serde_json::from_str::<AnyResourceId>("\" AMI-1234ABCD \"").is_err());
+        assert!(serde_json::from_str::<AwsRegionId>("\"EU-WEST-1 \"").is_err());
+    }
+
+    #[test]
+    fn test_sort_dedup() {
+        let vol = |s: &str| s.parse::<AwsVolumeId>().unwrap();
+        let mut ids = vec![
+            vol("vol-1a2b3c4d5e6f7j8h9"),
+            vol("vol-bbbbbbbb"),
+            vol("vol-aaaaaaaa"),
+            vol("vol-bbbbbbbb"),
+            vol("vol-1a2b3c4d5e6f7j8h9"),
+        ];
+        sort_dedup(&mut ids);
+        assert_eq!(
+            ids,
+            [
+                vol("vol-aaaaaaaa"),
+                vol("vol-bbbbbbbb"),
+                vol("vol-1a2b3c4d5e6f7j8h9"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_options_strip_angle_brackets() {
+        let strict = ParseOptions::default();
+        assert!(strict.parse::<AwsAmiId>("<ami-12345678>").is_err());
+
+        let lenient = ParseOptions::default().strip_angle_brackets(true);
+        assert_eq!(
+            lenient.parse::<AwsAmiId>("<ami-12345678>").unwrap(),
+            "ami-12345678".parse::<AwsAmiId>().unwrap()
+        );
+        assert!(lenient.parse::<AwsAmiId>("ami-12345678").is_ok());
+        assert!(lenient.parse::<AwsAmiId>("<<ami-12345678>>").is_err());
+        assert!(lenient.parse::<AwsAmiId>("<ami-12345678").is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_load_ids_from_reader_invalid() {
+        let input = "i-1234abcd\nvpc-1234abcd\n";
+        let result = load_ids_from_reader::<_, AwsInstanceId>(Cursor::new(input));
+        assert!(matches!(result, Err(Error::General(_))));
+    }
+
+    #[test]
+    fn test_error_debug() {
+        let err = AwsAmiId::try_from("amx-12345678").unwrap_err();
+        assert_eq!(
+            format!("{err:?}"),
+            "General(GeneralResourceError { target_type: \"AwsAmiId\", input: \"amx-12345678\", error_detail: WrongPrefix(\"ami-\") })"
+        );
+    }
 }
@@ -0,0 +1,25 @@
+//! # Prelude
+//!
+//! Re-exports the commonly used types and traits:
+//!
+//! ```
+//! use aws_resource_id::prelude::*;
+//!
+//! let ami: AwsAmiId = "ami-1234abcd".parse().unwrap();
+//! let region: AwsRegionId = "eu-west-1".parse().unwrap();
+//! assert_eq!(ami.to_string(), "ami-1234abcd");
+//! assert_eq!(region, AwsRegionId::EuWest1);
+//! ```
+pub use crate::{
+    AnyResourceId, Arn, ArnConstraint, AwsAccountId, AwsAmiId, AwsCloudFormationStackId,
+    AwsCustomerGatewayId, AwsEfsFileSystemId, AwsEfsMountTargetId,
+    AwsElasticBeanstalkEnvironmentId, AwsElasticIpAssociationId, AwsElasticIpId, AwsInstanceId,
+    AwsInternetGatewayId, AwsKeyPairId, AwsLoadBalancerId, AwsNatGatewayId, AwsNetworkAclId,
+    AwsNetworkInterfaceId, AwsPlacementGroupId, AwsRdsInstanceId, AwsRedshiftClusterId,
+    AwsRegionId, AwsRouteTableId, AwsSecurityGroupId, AwsSnapshotId, AwsSubnetId, AwsTargetGroupId,
+    AwsTransitGatewayAttachmentId, AwsTransitGatewayConnectPeerId, AwsTransitGatewayId,
+    AwsTransitGatewayMulticastDomainId, AwsTransitGatewayRouteTableId, AwsVolumeId, AwsVpcId,
+    AwsVpnConnectionId, AwsVpnGatewayId, Ec2KeyPairName, Error, IdOrWildcard, IdPattern,
+    IntoRegion, RawResourceId, RegionAccount, RegionScopedId, ResourceId, ResourceIdVec,
+    ResourceInventory, ResourceSpec, SharedResourceRef,
+};
@@ -1,319 +1,1915 @@
 //! # AWS Region ID
-use std::{convert::TryFrom, fmt, str::FromStr};
+//!
+//! Accessors are `#[must_use]`, so an accidentally discarded result is caught:
+//!
+//! ```compile_fail
+//! #![deny(unused_must_use)]
+//! aws_resource_id::AwsRegionId::EuWest1.long_name();
+//! ```
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+/// The date of the AWS regions list the region table was last verified
+/// against, to check which dataset a binary was built with, e.g. when a new
+/// region doesn't parse
+///
+/// Regions launched after the date are missing from the table.
+pub const DATA_VERSION: &str = "2025-09-30";
+
+/// Returns [`DATA_VERSION`], e.g. for logging
+#[must_use]
+pub fn data_version() -> &'static str {
+    DATA_VERSION
+}
 
 /// Error encountered when parsing an AWS region
 #[derive(Debug, thiserror::Error)]
 #[error("Unknown region: {0}")]
 pub struct RegionError(String);
 
-/// AWS Region ID
+/// Error encountered when a region is qualified with a partition it doesn't
+/// belong to, e.g. `aws-cn:us-east-1`
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "region {region} belongs to the \"{}\" partition, not \"{partition}\"",
+    region.partition().as_str()
+)]
+pub struct PartitionMismatchError {
+    /// The parsed region
+    region: AwsRegionId,
+    /// The given partition, it may be an unknown one
+    partition: String,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PartitionMismatchError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serialize_error(
+            serializer,
+            "PartitionMismatchError",
+            "partition_mismatch",
+            Some(&format!("{}:{}", self.partition, self.region)),
+            self,
+        )
+    }
+}
+
+/// Generates [`AwsRegionId`] with its conversions and per-region data from a
+/// single list, so adding a region is a one-line change
+macro_rules! regions {
+    ($(
+        $variant:ident, $code:literal, $name:literal, $partition:ident, $geography:ident,
+        country = $country:literal, opt_in = $opt_in:literal, fips = $fips:literal, dualstack = $dualstack:literal,
+        index = $index:literal;
+    )*) => {
+        /// AWS Region ID
+        ///
+        /// Variants are declared in the alphabetical order of their codes, so the
+        /// derived `Ord` matches the ordering of the region strings, e.g. a
+        /// `BTreeSet<AwsRegionId>` iterates alphabetically.
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum AwsRegionId {
+            $(
+                #[doc = $name]
+                $variant,
+            )*
+        }
+
+        impl AwsRegionId {
+            /// All the regions, in the declaration order
+            pub const ALL: &'static [AwsRegionId] = &[$(AwsRegionId::$variant,)*];
+
+            /// Human-readable name of the region, e.g. `Europe (Frankfurt)`
+            #[must_use]
+            pub fn long_name(&self) -> &'static str {
+                match self {
+                    $(AwsRegionId::$variant => $name,)*
+                }
+            }
+
+            /// ISO 3166-1 alpha-2 code of the country or territory of the
+            /// region, e.g. `DE` for `eu-central-1`
+            #[must_use]
+            pub fn country_code(&self) -> &'static str {
+                match self {
+                    $(AwsRegionId::$variant => $country,)*
+                }
+            }
+
+            /// Geographic area of the region
+            #[must_use]
+            pub fn geography(&self) -> Geography {
+                match self {
+                    $(AwsRegionId::$variant => Geography::$geography,)*
+                }
+            }
+
+            /// The partition of the region
+            #[must_use]
+            pub fn partition(&self) -> AwsPartition {
+                match self {
+                    $(AwsRegionId::$variant => AwsPartition::$partition,)*
+                }
+            }
+
+            /// Checks if the region is disabled by default and has to be enabled per
+            /// account, which is the case for the regions launched after March 2019
+            #[must_use]
+            pub fn is_opt_in(&self) -> bool {
+                match self {
+                    $(AwsRegionId::$variant => $opt_in,)*
+                }
+            }
+
+            /// Checks if the region has FIPS endpoints
+            ///
+            /// It's a best-effort table, availability varies between services, see
+            /// <https://aws.amazon.com/compliance/fips/>
+            #[must_use]
+            pub fn supports_fips(&self) -> bool {
+                match self {
+                    $(AwsRegionId::$variant => $fips,)*
+                }
+            }
+
+            /// Checks if the region has dual-stack (IPv4 and IPv6) endpoints
+            ///
+            /// It's a best-effort table based on S3 dual-stack endpoints,
+            /// availability varies between services.
+            #[must_use]
+            pub fn supports_dualstack(&self) -> bool {
+                match self {
+                    $(AwsRegionId::$variant => $dualstack,)*
+                }
+            }
+
+            /// Stable number of the region used by compact serialization
+            #[cfg(feature = "serde")]
+            fn compact_index(&self) -> u8 {
+                match self {
+                    $(AwsRegionId::$variant => $index,)*
+                }
+            }
+
+            /// The region by its [`Self::compact_index`]
+            #[cfg(feature = "serde")]
+            fn from_compact_index(index: u8) -> Option<Self> {
+                match index {
+                    $($index => Some(AwsRegionId::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl TryFrom<&str> for AwsRegionId {
+            type Error = crate::Error;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                match s {
+                    $($code => Ok(AwsRegionId::$variant),)*
+                    _ => Err(RegionError(s.into()).into()),
+                }
+            }
+        }
+
+        impl From<AwsRegionId> for &'static str {
+            fn from(region: AwsRegionId) -> Self {
+                match region {
+                    $(AwsRegionId::$variant => $code,)*
+                }
+            }
+        }
+    };
+}
+
+// Variants are kept in the alphabetical order of their codes, see the enum
+// docs. Fields: variant, code, long name, partition, geography, ISO country
+// code, opt-in, FIPS and dual-stack endpoints availability, and the compact
+// serialization index.
+// Indexes are persisted by binary formats, so they must never change, new
+// regions take the next free one. Update `DATA_VERSION` after verifying the
+// table against the AWS regions list.
+regions! {
+    AfSouth1, "af-south-1", "Africa (Cape Town)", Aws, Africa, country = "ZA", opt_in = true, fips = false, dualstack = true, index = 0;
+    ApEast1, "ap-east-1", "Asia Pacific (Hong Kong)", Aws, AsiaPacific, country = "HK", opt_in = true, fips = false, dualstack = true, index = 1;
+    ApEast2, "ap-east-2", "Asia Pacific (Taipei)", Aws, AsiaPacific, country = "TW", opt_in = true, fips = false, dualstack = true, index = 29;
+    ApNortheast1, "ap-northeast-1", "Asia Pacific (Tokyo)", Aws, AsiaPacific, country = "JP", opt_in = false, fips = false, dualstack = true, index = 2;
+    ApNortheast2, "ap-northeast-2", "Asia Pacific (Seoul)", Aws, AsiaPacific, country = "KR", opt_in = false, fips = false, dualstack = true, index = 3;
+    ApNortheast3, "ap-northeast-3", "Asia Pacific (Osaka)", Aws, AsiaPacific, country = "JP", opt_in = false, fips = false, dualstack = true, index = 4;
+    ApSouth1, "ap-south-1", "Asia Pacific (Mumbai)", Aws, AsiaPacific, country = "IN", opt_in = false, fips = false, dualstack = true, index = 5;
+    ApSouth2, "ap-south-2", "Asia Pacific (Hyderabad)", Aws, AsiaPacific, country = "IN", opt_in = true, fips = false, dualstack = true, index = 6;
+    ApSoutheast1, "ap-southeast-1", "Asia Pacific (Singapore)", Aws, AsiaPacific, country = "SG", opt_in = false, fips = false, dualstack = true, index = 7;
+    ApSoutheast2, "ap-southeast-2", "Asia Pacific (Sydney)", Aws, AsiaPacific, country = "AU", opt_in = false, fips = false, dualstack = true, index = 8;
+    ApSoutheast3, "ap-southeast-3", "Asia Pacific (Jakarta)", Aws, AsiaPacific, country = "ID", opt_in = true, fips = false, dualstack = true, index = 9;
+    ApSoutheast4, "ap-southeast-4", "Asia Pacific (Melbourne)", Aws, AsiaPacific, country = "AU", opt_in = true, fips = false, dualstack = true, index = 10;
+    ApSoutheast5, "ap-southeast-5", "Asia Pacific (Malaysia)", Aws, AsiaPacific, country = "MY", opt_in = true, fips = false, dualstack = true, index = 30;
+    ApSoutheast6, "ap-southeast-6", "Asia Pacific (New Zealand)", Aws, AsiaPacific, country = "NZ", opt_in = true, fips = false, dualstack = true, index = 31;
+    ApSoutheast7, "ap-southeast-7", "Asia Pacific (Thailand)", Aws, AsiaPacific, country = "TH", opt_in = true, fips = false, dualstack = true, index = 32;
+    CaCentral1, "ca-central-1", "Canada (Central)", Aws, NorthAmerica, country = "CA", opt_in = false, fips = true, dualstack = true, index = 11;
+    CaWest1, "ca-west-1", "Canada West (Calgary)", Aws, NorthAmerica, country = "CA", opt_in = true, fips = true, dualstack = true, index = 12;
+    CnNorth1, "cn-north-1", "China (Beijing)", AwsCn, AsiaPacific, country = "CN", opt_in = false, fips = false, dualstack = true, index = 36;
+    CnNorthwest1, "cn-northwest-1", "China (Ningxia)", AwsCn, AsiaPacific, country = "CN", opt_in = false, fips = false, dualstack = true, index = 37;
+    EuCentral1, "eu-central-1", "Europe (Frankfurt)", Aws, Europe, country = "DE", opt_in = false, fips = false, dualstack = true, index = 13;
+    EuCentral2, "eu-central-2", "Europe (Zurich)", Aws, Europe, country = "CH", opt_in = true, fips = false, dualstack = true, index = 14;
+    EuNorth1, "eu-north-1", "Europe (Stockholm)", Aws, Europe, country = "SE", opt_in = false, fips = false, dualstack = true, index = 15;
+    EuSouth1, "eu-south-1", "Europe (Milan)", Aws, Europe, country = "IT", opt_in = true, fips = false, dualstack = true, index = 16;
+    EuSouth2, "eu-south-2", "Europe (Spain)", Aws, Europe, country = "ES", opt_in = true, fips = false, dualstack = true, index = 17;
+    EuWest1, "eu-west-1", "Europe (Ireland)", Aws, Europe, country = "IE", opt_in = false, fips = false, dualstack = true, index = 18;
+    EuWest2, "eu-west-2", "Europe (London)", Aws, Europe, country = "GB", opt_in = false, fips = false, dualstack = true, index = 19;
+    EuWest3, "eu-west-3", "Europe (Paris)", Aws, Europe, country = "FR", opt_in = false, fips = false, dualstack = true, index = 20;
+    IlCentral1, "il-central-1", "Israel (Tel Aviv)", Aws, MiddleEast, country = "IL", opt_in = true, fips = false, dualstack = true, index = 21;
+    MeCentral1, "me-central-1", "Middle East (UAE)", Aws, MiddleEast, country = "AE", opt_in = true, fips = false, dualstack = true, index = 22;
+    MeSouth1, "me-south-1", "Middle East (Bahrain)", Aws, MiddleEast, country = "BH", opt_in = true, fips = false, dualstack = true, index = 23;
+    MxCentral1, "mx-central-1", "Mexico (Central)", Aws, NorthAmerica, country = "MX", opt_in = true, fips = false, dualstack = true, index = 33;
+    SaEast1, "sa-east-1", "South America (São Paulo)", Aws, SouthAmerica, country = "BR", opt_in = false, fips = false, dualstack = true, index = 24;
+    UsEast1, "us-east-1", "US East (N. Virginia)", Aws, NorthAmerica, country = "US", opt_in = false, fips = true, dualstack = true, index = 25;
+    UsEast2, "us-east-2", "US East (Ohio)", Aws, NorthAmerica, country = "US", opt_in = false, fips = true, dualstack = true, index = 26;
+    UsGovEast1, "us-gov-east-1", "AWS GovCloud (US-East)", AwsUsGov, NorthAmerica, country = "US", opt_in = false, fips = true, dualstack = true, index = 34;
+    UsGovWest1, "us-gov-west-1", "AWS GovCloud (US-West)", AwsUsGov, NorthAmerica, country = "US", opt_in = false, fips = true, dualstack = true, index = 35;
+    UsWest1, "us-west-1", "US West (N. California)", Aws, NorthAmerica, country = "US", opt_in = false, fips = true, dualstack = true, index = 27;
+    UsWest2, "us-west-2", "US West (Oregon)", Aws, NorthAmerica, country = "US", opt_in = false, fips = true, dualstack = true, index = 28;
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RegionError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serialize_error(
+            serializer,
+            "RegionError",
+            "unknown_region",
+            Some(self.0.as_str()),
+            self,
+        )
+    }
+}
+
+/// AWS partition, a group of regions sharing the same global services
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum AwsPartition {
+    /// Standard AWS regions
+    Aws,
+    /// AWS China regions
+    AwsCn,
+    /// AWS GovCloud (US) regions
+    AwsUsGov,
+}
+
+impl AwsPartition {
+    /// The partition name as used in ARNs, e.g. `aws-cn`
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Aws => "aws",
+            Self::AwsCn => "aws-cn",
+            Self::AwsUsGov => "aws-us-gov",
+        }
+    }
+
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        match s {
+            "aws" => Some(Self::Aws),
+            "aws-cn" => Some(Self::AwsCn),
+            "aws-us-gov" => Some(Self::AwsUsGov),
+            _ => None,
+        }
+    }
+
+    /// The domain suffix of the partition endpoints, e.g. `amazonaws.com.cn`
+    #[must_use]
+    pub fn dns_suffix(&self) -> &'static str {
+        match self {
+            Self::Aws | Self::AwsUsGov => "amazonaws.com",
+            Self::AwsCn => "amazonaws.com.cn",
+        }
+    }
+}
+
+/// Geographic area a region is located in
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum AwsRegionId {
-    /// Africa (Cape Town)
-    AfSouth1,
-    /// Asia Pacific (Hong Kong)
-    ApEast1,
-    /// Asia Pacific (Tokyo)
-    ApNortheast1,
-    /// Asia Pacific (Seoul)
-    ApNortheast2,
-    /// Asia Pacific (Osaka)
-    ApNortheast3,
-    /// Asia Pacific (Mumbai)
-    ApSouth1,
-    /// Asia Pacific (Hyderabad)
-    ApSouth2,
-    /// Asia Pacific (Singapore)
-    ApSoutheast1,
-    /// Asia Pacific (Sydney)
-    ApSoutheast2,
-    /// Asia Pacific (Jakarta)
-    ApSoutheast3,
-    /// Asia Pacific (Melbourne)
-    ApSoutheast4,
-    /// Canada (Central)
-    CaCentral1,
-    /// Canada West (Calgary)
-    CaWest1,
-    /// Europe (Frankfurt)
-    EuCentral1,
-    /// Europe (Zurich)
-    EuCentral2,
-    /// Europe (Stockholm)
-    EuNorth1,
-    /// Europe (Milan)
-    EuSouth1,
-    /// Europe (Spain)
-    EuSouth2,
-    /// Europe (Ireland)
-    EuWest1,
-    /// Europe (London)
-    EuWest2,
-    /// Europe (Paris)
-    EuWest3,
-    /// Israel (Tel Aviv)
-    IlCentral1,
-    /// Middle East (UAE)
-    MeCentral1,
-    /// Middle East (Bahrain)
-    MeSouth1,
-    /// South America (São Paulo)
-    SaEast1,
-    /// US East (N. Virginia)
-    UsEast1,
-    /// US East (Ohio)
-    UsEast2,
-    /// US West (N. California)
-    UsWest1,
-    /// US West (Oregon)
-    UsWest2,
-}
-
-impl TryFrom<&str> for AwsRegionId {
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Geography {
+    /// Africa
+    Africa,
+    /// Asia Pacific
+    AsiaPacific,
+    /// Europe
+    Europe,
+    /// Middle East, including Israel
+    MiddleEast,
+    /// North America: Canada and the US
+    NorthAmerica,
+    /// South America
+    SouthAmerica,
+}
+
+/// A set of region capabilities, see [`AwsRegionId::capabilities`]
+///
+/// ```
+/// use aws_resource_id::{AwsRegionId, RegionCapabilities};
+///
+/// let caps = AwsRegionId::UsEast1.capabilities();
+/// assert!(caps.contains(RegionCapabilities::FIPS | RegionCapabilities::PARTITION_AWS));
+/// assert!(!caps.contains(RegionCapabilities::OPT_IN));
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RegionCapabilities(u8);
+
+impl RegionCapabilities {
+    /// The region is disabled by default and has to be enabled per account
+    pub const OPT_IN: Self = Self(1);
+    /// The region has FIPS endpoints
+    pub const FIPS: Self = Self(1 << 1);
+    /// The region has dual-stack endpoints
+    pub const DUALSTACK: Self = Self(1 << 2);
+    /// The region is in the standard partition
+    pub const PARTITION_AWS: Self = Self(1 << 3);
+    /// The region is in the China partition
+    pub const PARTITION_AWS_CN: Self = Self(1 << 4);
+    /// The region is in the GovCloud (US) partition
+    pub const PARTITION_AWS_US_GOV: Self = Self(1 << 5);
+
+    /// Checks if all the given flags are set
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// The raw bits of the set
+    #[must_use]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+}
+
+impl core::ops::BitOr for RegionCapabilities {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for RegionCapabilities {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl From<AwsPartition> for RegionCapabilities {
+    fn from(partition: AwsPartition) -> Self {
+        match partition {
+            AwsPartition::Aws => Self::PARTITION_AWS,
+            AwsPartition::AwsCn => Self::PARTITION_AWS_CN,
+            AwsPartition::AwsUsGov => Self::PARTITION_AWS_US_GOV,
+        }
+    }
+}
+
+/// The region of a service endpoint hostname, see
+/// [`AwsRegionId::from_endpoint`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EndpointRegion {
+    region: AwsRegionId,
+    fips: bool,
+}
+
+impl EndpointRegion {
+    /// The base region of the endpoint
+    #[must_use]
+    pub fn region(&self) -> AwsRegionId {
+        self.region
+    }
+
+    /// Checks if it's a FIPS (or the older FISMA) endpoint, e.g.
+    /// `ec2-fips.us-east-1.amazonaws.com`
+    #[must_use]
+    pub fn is_fips(&self) -> bool {
+        self.fips
+    }
+}
+
+/// Metadata of a region, see [`AwsRegionId::catalog`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RegionMetadata {
+    /// The region
+    pub id: AwsRegionId,
+    /// Human-readable name, e.g. `Europe (Frankfurt)`
+    pub name: &'static str,
+    /// The partition
+    pub partition: AwsPartition,
+    /// The geographic area
+    pub geography: Geography,
+    /// Whether the region has to be enabled per account
+    pub opt_in: bool,
+}
+
+/// All the regions with their metadata along with [`DATA_VERSION`], e.g. to
+/// serve a region list to front-ends
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RegionCatalog {
+    /// The date of the region dataset
+    pub data_version: &'static str,
+    /// The regions
+    pub regions: Vec<RegionMetadata>,
+}
+
+impl Default for RegionCatalog {
+    fn default() -> Self {
+        Self {
+            data_version: DATA_VERSION,
+            regions: AwsRegionId::catalog(),
+        }
+    }
+}
+
+/// Either a regular region or the global pseudo-region of a partition, as
+/// used by the SDK for global services like IAM (e.g. `aws-global`)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RegionOrGlobal {
+    /// A regular region
+    Region(AwsRegionId),
+    /// The global pseudo-region of the partition
+    Global(AwsPartition),
+}
+
+impl AwsRegionId {
+    /// Metadata of all the regions, in the order of [`Self::ALL`]
+    #[must_use]
+    pub fn catalog() -> Vec<RegionMetadata> {
+        Self::ALL.iter().map(AwsRegionId::metadata).collect()
+    }
+
+    /// Metadata of the region
+    #[must_use]
+    pub fn metadata(&self) -> RegionMetadata {
+        RegionMetadata {
+            id: *self,
+            name: self.long_name(),
+            partition: self.partition(),
+            geography: self.geography(),
+            opt_in: self.is_opt_in(),
+        }
+    }
+
+    /// Reads the region from an environment variable, e.g. `AWS_REGION`
+    ///
+    /// Unset and non-unicode variables result in [`crate::Error::EnvVar`],
+    /// invalid regions in [`crate::Error::Region`].
+    #[cfg(feature = "std")]
+    pub fn from_env(var: &str) -> Result<AwsRegionId, crate::Error> {
+        let value = std::env::var(var).map_err(|source| crate::Error::EnvVar {
+            var: var.into(),
+            source,
+        })?;
+        Self::try_from(value)
+    }
+
+    /// Extracts the region from a regional endpoint hostname, e.g.
+    /// `sqs.eu-west-1.amazonaws.com` or `ec2-fips.us-east-1.amazonaws.com`
+    ///
+    /// The scheme, port and path are ignored if present.
+    pub fn from_endpoint(endpoint: &str) -> Result<EndpointRegion, crate::Error> {
+        let error = || RegionError(endpoint.into());
+        let host = endpoint
+            .split_once("://")
+            .map_or(endpoint, |(_, rest)| rest);
+        let host = host.split(['/', ':']).next().unwrap_or_default();
+        let host = host
+            .strip_suffix(AwsPartition::AwsCn.dns_suffix())
+            .or_else(|| host.strip_suffix(AwsPartition::Aws.dns_suffix()))
+            .and_then(|h| h.strip_suffix('.'))
+            .ok_or_else(error)?;
+        let (service, region) = host.rsplit_once('.').ok_or_else(error)?;
+        let fips = service
+            .split('.')
+            .any(|label| label.ends_with("-fips") || label.ends_with("-fisma") || label == "fips");
+        let region = Self::try_from(region).map_err(|_| error())?;
+        Ok(EndpointRegion { region, fips })
+    }
+
+    /// Parses a region optionally qualified with its partition, e.g.
+    /// `aws:us-east-1`
+    ///
+    /// Unknown regions result in [`crate::Error::Region`], partitions other
+    /// than the region's one in [`crate::Error::PartitionMismatch`].
+    pub fn try_from_qualified(s: &str) -> Result<AwsRegionId, crate::Error> {
+        let Some((partition, region)) = s.split_once(':') else {
+            return Self::try_from(s);
+        };
+        let region = Self::try_from(region)?;
+        if AwsPartition::parse(partition) != Some(region.partition()) {
+            return Err(PartitionMismatchError {
+                region,
+                partition: partition.into(),
+            }
+            .into());
+        }
+        Ok(region)
+    }
+
+    /// Parses a region, additionally accepting the SDK global pseudo-regions:
+    /// `aws-global`, `aws-cn-global` and `aws-us-gov-global`
+    pub fn try_from_including_global(s: &str) -> Result<RegionOrGlobal, crate::Error> {
+        match s {
+            "aws-global" => Ok(RegionOrGlobal::Global(AwsPartition::Aws)),
+            "aws-cn-global" => Ok(RegionOrGlobal::Global(AwsPartition::AwsCn)),
+            "aws-us-gov-global" => Ok(RegionOrGlobal::Global(AwsPartition::AwsUsGov)),
+            _ => Self::try_from(s).map(RegionOrGlobal::Region),
+        }
+    }
+
+    /// The region code and its human-readable name, e.g. for table rendering
+    #[must_use]
+    pub fn id_and_name(&self) -> (&'static str, &'static str) {
+        ((*self).into(), self.long_name())
+    }
+
+    /// The region code in uppercase, e.g. `US-EAST-1`, for UIs
+    #[must_use]
+    pub fn to_uppercase_string(&self) -> String {
+        self.as_ref().to_ascii_uppercase()
+    }
+
+    /// The region field of an ARN of the service, empty for global services
+    /// like `iam`, see [`is_global_service`]
+    #[must_use]
+    pub fn arn_region_field(&self, service: &str) -> &'static str {
+        if is_global_service(service) {
+            ""
+        } else {
+            (*self).into()
+        }
+    }
+
+    /// Replaces `${AWS::Region}` and `{region}` placeholders in the template
+    /// with the region, e.g. in CloudFormation-style ARN templates
+    #[must_use]
+    pub fn substitute_into(&self, template: &str) -> String {
+        template
+            .replace("${AWS::Region}", self.as_ref())
+            .replace("{region}", self.as_ref())
+    }
+
+    /// The regional endpoint URL of a service, e.g.
+    /// `https://ec2.us-east-1.amazonaws.com`
+    #[must_use]
+    pub fn endpoint_url(&self, service: &str) -> String {
+        endpoint_url(service, self.as_ref(), self.partition())
+    }
+
+    /// The regional STS endpoint host, e.g. `sts.eu-west-1.amazonaws.com`
+    ///
+    /// Prefer it over the global endpoint: regional tokens are valid in all
+    /// regions, and the regional endpoint keeps working if `us-east-1` is
+    /// unavailable.
+    #[must_use]
+    pub fn sts_endpoint(&self) -> String {
+        endpoint_host("sts", self.as_ref(), self.partition())
+    }
+
+    /// The legacy global STS endpoint host of the region partition, e.g.
+    /// `sts.amazonaws.com`, which is served from `us-east-1`
+    #[must_use]
+    pub fn sts_global_endpoint(&self) -> &'static str {
+        match self.partition() {
+            AwsPartition::Aws => "sts.amazonaws.com",
+            AwsPartition::AwsCn => "sts.cn-north-1.amazonaws.com.cn",
+            AwsPartition::AwsUsGov => "sts.us-gov-west-1.amazonaws.com",
+        }
+    }
+
+    /// The regional endpoint URL of a service parsed into [`url::Url`]
+    ///
+    /// Fails if the service name isn't a valid host label.
+    #[cfg(feature = "url")]
+    pub fn endpoint_url_parsed(&self, service: &str) -> Result<url::Url, url::ParseError> {
+        url::Url::parse(&self.endpoint_url(service))
+    }
+
+    /// Checks if AWS has announced the retirement of the region, e.g. to warn
+    /// about deployments to it
+    ///
+    /// The list is updated as AWS announces retirements, currently it's empty.
+    #[must_use]
+    pub fn is_retiring(&self) -> bool {
+        false
+    }
+
+    /// All the capabilities of the region in one value
+    #[must_use]
+    pub fn capabilities(&self) -> RegionCapabilities {
+        let mut caps = RegionCapabilities::from(self.partition());
+        if self.is_opt_in() {
+            caps |= RegionCapabilities::OPT_IN;
+        }
+        if self.supports_fips() {
+            caps |= RegionCapabilities::FIPS;
+        }
+        if self.supports_dualstack() {
+            caps |= RegionCapabilities::DUALSTACK;
+        }
+        caps
+    }
+
+    /// Approximate coordinates (latitude, longitude) of the region data
+    /// centers, usually the city the region is named after
+    #[cfg(feature = "std")]
+    fn coordinates(&self) -> (f64, f64) {
+        match self {
+            AwsRegionId::AfSouth1 => (-33.92, 18.42),
+            AwsRegionId::ApEast1 => (22.32, 114.17),
+            AwsRegionId::ApEast2 => (25.03, 121.57),
+            AwsRegionId::ApNortheast1 => (35.68, 139.69),
+            AwsRegionId::ApNortheast2 => (37.57, 126.98),
+            AwsRegionId::ApNortheast3 => (34.69, 135.50),
+            AwsRegionId::ApSouth1 => (19.08, 72.88),
+            AwsRegionId::ApSouth2 => (17.39, 78.49),
+            AwsRegionId::ApSoutheast1 => (1.35, 103.82),
+            AwsRegionId::ApSoutheast2 => (-33.87, 151.21),
+            AwsRegionId::ApSoutheast3 => (-6.21, 106.85),
+            AwsRegionId::ApSoutheast4 => (-37.81, 144.96),
+            AwsRegionId::ApSoutheast5 => (3.13, 101.69),
+            AwsRegionId::ApSoutheast6 => (-36.85, 174.76),
+            AwsRegionId::ApSoutheast7 => (13.76, 100.50),
+            AwsRegionId::CaCentral1 => (45.50, -73.57),
+            AwsRegionId::CaWest1 => (51.05, -114.07),
+            AwsRegionId::CnNorth1 => (39.90, 116.41),
+            AwsRegionId::CnNorthwest1 => (38.49, 106.23),
+            AwsRegionId::EuCentral1 => (50.11, 8.68),
+            AwsRegionId::EuCentral2 => (47.38, 8.54),
+            AwsRegionId::EuNorth1 => (59.33, 18.07),
+            AwsRegionId::EuSouth1 => (45.46, 9.19),
+            AwsRegionId::EuSouth2 => (41.65, -0.88),
+            AwsRegionId::EuWest1 => (53.35, -6.26),
+            AwsRegionId::EuWest2 => (51.51, -0.13),
+            AwsRegionId::EuWest3 => (48.86, 2.35),
+            AwsRegionId::IlCentral1 => (32.09, 34.78),
+            AwsRegionId::MeCentral1 => (25.20, 55.27),
+            AwsRegionId::MeSouth1 => (26.07, 50.56),
+            AwsRegionId::MxCentral1 => (20.59, -100.39),
+            AwsRegionId::SaEast1 => (-23.55, -46.63),
+            AwsRegionId::UsEast1 => (39.04, -77.49),
+            AwsRegionId::UsEast2 => (39.96, -83.00),
+            AwsRegionId::UsGovEast1 => (39.96, -83.00),
+            AwsRegionId::UsGovWest1 => (45.52, -122.68),
+            AwsRegionId::UsWest1 => (37.34, -121.89),
+            AwsRegionId::UsWest2 => (45.84, -119.70),
+        }
+    }
+
+    /// IANA time zone name of the region, e.g. `America/New_York`
+    ///
+    /// It's an approximation: the zone of the city the region is named after,
+    /// or its largest city, while data centers may span nearby zones.
+    #[must_use]
+    pub fn timezone_name(&self) -> &'static str {
+        match self {
+            AwsRegionId::AfSouth1 => "Africa/Johannesburg",
+            AwsRegionId::ApEast1 => "Asia/Hong_Kong",
+            AwsRegionId::ApEast2 => "Asia/Taipei",
+            AwsRegionId::ApNortheast1 => "Asia/Tokyo",
+            AwsRegionId::ApNortheast2 => "Asia/Seoul",
+            AwsRegionId::ApNortheast3 => "Asia/Tokyo",
+            AwsRegionId::ApSouth1 => "Asia/Kolkata",
+            AwsRegionId::ApSouth2 => "Asia/Kolkata",
+            AwsRegionId::ApSoutheast1 => "Asia/Singapore",
+            AwsRegionId::ApSoutheast2 => "Australia/Sydney",
+            AwsRegionId::ApSoutheast3 => "Asia/Jakarta",
+            AwsRegionId::ApSoutheast4 => "Australia/Melbourne",
+            AwsRegionId::ApSoutheast5 => "Asia/Kuala_Lumpur",
+            AwsRegionId::ApSoutheast6 => "Pacific/Auckland",
+            AwsRegionId::ApSoutheast7 => "Asia/Bangkok",
+            AwsRegionId::CaCentral1 => "America/Toronto",
+            AwsRegionId::CaWest1 => "America/Edmonton",
+            AwsRegionId::CnNorth1 => "Asia/Shanghai",
+            AwsRegionId::CnNorthwest1 => "Asia/Shanghai",
+            AwsRegionId::EuCentral1 => "Europe/Berlin",
+            AwsRegionId::EuCentral2 => "Europe/Zurich",
+            AwsRegionId::EuNorth1 => "Europe/Stockholm",
+            AwsRegionId::EuSouth1 => "Europe/Rome",
+            AwsRegionId::EuSouth2 => "Europe/Madrid",
+            AwsRegionId::EuWest1 => "Europe/Dublin",
+            AwsRegionId::EuWest2 => "Europe/London",
+            AwsRegionId::EuWest3 => "Europe/Paris",
+            AwsRegionId::IlCentral1 => "Asia/Jerusalem",
+            AwsRegionId::MeCentral1 => "Asia/Dubai",
+            AwsRegionId::MeSouth1 => "Asia/Bahrain",
+            AwsRegionId::MxCentral1 => "America/Mexico_City",
+            AwsRegionId::SaEast1 => "America/Sao_Paulo",
+            AwsRegionId::UsEast1 => "America/New_York",
+            AwsRegionId::UsEast2 => "America/New_York",
+            AwsRegionId::UsGovEast1 => "America/New_York",
+            AwsRegionId::UsGovWest1 => "America/Los_Angeles",
+            AwsRegionId::UsWest1 => "America/Los_Angeles",
+            AwsRegionId::UsWest2 => "America/Los_Angeles",
+        }
+    }
+
+    /// Time zone of the region, see [`Self::timezone_name`] on the
+    /// approximation
+    #[cfg(feature = "chrono-tz")]
+    #[must_use]
+    pub fn timezone(&self) -> chrono_tz::Tz {
+        match self {
+            AwsRegionId::AfSouth1 => chrono_tz::Africa::Johannesburg,
+            AwsRegionId::ApEast1 => chrono_tz::Asia::Hong_Kong,
+            AwsRegionId::ApEast2 => chrono_tz::Asia::Taipei,
+            AwsRegionId::ApNortheast1 => chrono_tz::Asia::Tokyo,
+            AwsRegionId::ApNortheast2 => chrono_tz::Asia::Seoul,
+            AwsRegionId::ApNortheast3 => chrono_tz::Asia::Tokyo,
+            AwsRegionId::ApSouth1 => chrono_tz::Asia::Kolkata,
+            AwsRegionId::ApSouth2 => chrono_tz::Asia::Kolkata,
+            AwsRegionId::ApSoutheast1 => chrono_tz::Asia::Singapore,
+            AwsRegionId::ApSoutheast2 => chrono_tz::Australia::Sydney,
+            AwsRegionId::ApSoutheast3 => chrono_tz::Asia::Jakarta,
+            AwsRegionId::ApSoutheast4 => chrono_tz::Australia::Melbourne,
+            AwsRegionId::ApSoutheast5 => chrono_tz::Asia::Kuala_Lumpur,
+            AwsRegionId::ApSoutheast6 => chrono_tz::Pacific::Auckland,
+            AwsRegionId::ApSoutheast7 => chrono_tz::Asia::Bangkok,
+            AwsRegionId::CaCentral1 => chrono_tz::America::Toronto,
+            AwsRegionId::CaWest1 => chrono_tz::America::Edmonton,
+            AwsRegionId::CnNorth1 => chrono_tz::Asia::Shanghai,
+            AwsRegionId::CnNorthwest1 => chrono_tz::Asia::Shanghai,
+            AwsRegionId::EuCentral1 => chrono_tz::Europe::Berlin,
+            AwsRegionId::EuCentral2 => chrono_tz::Europe::Zurich,
+            AwsRegionId::EuNorth1 => chrono_tz::Europe::Stockholm,
+            AwsRegionId::EuSouth1 => chrono_tz::Europe::Rome,
+            AwsRegionId::EuSouth2 => chrono_tz::Europe::Madrid,
+            AwsRegionId::EuWest1 => chrono_tz::Europe::Dublin,
+            AwsRegionId::EuWest2 => chrono_tz::Europe::London,
+            AwsRegionId::EuWest3 => chrono_tz::Europe::Paris,
+            AwsRegionId::IlCentral1 => chrono_tz::Asia::Jerusalem,
+            AwsRegionId::MeCentral1 => chrono_tz::Asia::Dubai,
+            AwsRegionId::MeSouth1 => chrono_tz::Asia::Bahrain,
+            AwsRegionId::MxCentral1 => chrono_tz::America::Mexico_City,
+            AwsRegionId::SaEast1 => chrono_tz::America::Sao_Paulo,
+            AwsRegionId::UsEast1 => chrono_tz::America::New_York,
+            AwsRegionId::UsEast2 => chrono_tz::America::New_York,
+            AwsRegionId::UsGovEast1 => chrono_tz::America::New_York,
+            AwsRegionId::UsGovWest1 => chrono_tz::America::Los_Angeles,
+            AwsRegionId::UsWest1 => chrono_tz::America::Los_Angeles,
+            AwsRegionId::UsWest2 => chrono_tz::America::Los_Angeles,
+        }
+    }
+
+    /// The region closest to the given coordinates, e.g. for latency-aware
+    /// region selection from a user's geolocation
+    ///
+    /// The distances are computed to approximate data center locations. Only
+    /// the regions of the standard partition are considered, as the others
+    /// need separate accounts.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn nearest_to(lat: f64, lon: f64) -> AwsRegionId {
+        let mut nearest = AwsRegionId::UsEast1;
+        let mut min_distance = f64::INFINITY;
+        for region in Self::ALL {
+            if region.partition() != AwsPartition::Aws {
+                continue;
+            }
+            let (region_lat, region_lon) = region.coordinates();
+            let distance = haversine_distance(lat, lon, region_lat, region_lon);
+            if distance < min_distance {
+                min_distance = distance;
+                nearest = *region;
+            }
+        }
+        nearest
+    }
+
+    /// Compares regions by their codes alphabetically, e.g. for display,
+    /// independently of the derived `Ord`
+    #[must_use]
+    pub fn cmp_by_name(&self, other: &AwsRegionId) -> core::cmp::Ordering {
+        self.as_ref().cmp(other.as_ref())
+    }
+
+    /// Checks if both regions are in the same geographic area, e.g. to prefer
+    /// nearby failover targets
+    #[must_use]
+    pub fn same_geography(&self, other: &AwsRegionId) -> bool {
+        self.geography() == other.geography()
+    }
+}
+
+/// Checks if an AWS service is global, i.e. its ARNs omit the region
+///
+/// The service is given by its ARN namespace, e.g. `iam` or `ec2`.
+#[must_use]
+pub fn is_global_service(service: &str) -> bool {
+    matches!(
+        service,
+        "cloudfront" | "iam" | "organizations" | "route53" | "waf"
+    )
+}
+
+/// Finds regions by a case-insensitive substring of their code or long name,
+/// or by their country code, e.g. for a region picker with search-as-you-type
+///
+/// An exact code match goes first, then code prefix and exact country code
+/// matches, then the rest in the order of [`AwsRegionId::ALL`]. An empty query
+/// returns all the regions.
+#[must_use]
+pub fn search(query: &str) -> Vec<AwsRegionId> {
+    let query = query.trim().to_lowercase();
+    let mut found: Vec<(u8, AwsRegionId)> = AwsRegionId::ALL
+        .iter()
+        .filter_map(|&region| {
+            let code = region.as_ref();
+            let rank = if code == query {
+                0
+            } else if code.starts_with(&query) || region.country_code().eq_ignore_ascii_case(&query)
+            {
+                1
+            } else if code.contains(&query) || region.long_name().to_lowercase().contains(&query) {
+                2
+            } else {
+                return None;
+            };
+            Some((rank, region))
+        })
+        .collect();
+    found.sort_by_key(|(rank, _)| *rank);
+    found.into_iter().map(|(_, region)| region).collect()
+}
+
+/// Parses a list of regions, e.g. from a config, reporting every invalid
+/// entry along with its index instead of stopping at the first one
+pub fn validate_regions<'a>(
+    regions: impl IntoIterator<Item = &'a str>,
+) -> Result<Vec<AwsRegionId>, Vec<(usize, crate::Error)>> {
+    let mut valid = Vec::new();
+    let mut errors = Vec::new();
+    for (i, region) in regions.into_iter().enumerate() {
+        match AwsRegionId::try_from(region) {
+            Ok(region) => valid.push(region),
+            Err(e) => errors.push((i, e)),
+        }
+    }
+    if errors.is_empty() {
+        Ok(valid)
+    } else {
+        Err(errors)
+    }
+}
+
+fn endpoint_host(service: &str, region: &str, partition: AwsPartition) -> String {
+    format!("{service}.{region}.{}", partition.dns_suffix())
+}
+
+fn endpoint_url(service: &str, region: &str, partition: AwsPartition) -> String {
+    format!("https://{}", endpoint_host(service, region, partition))
+}
+
+/// Great-circle distance in kilometers between two points given in degrees
+#[cfg(feature = "std")]
+fn haversine_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    const EARTH_RADIUS_KM: f64 = 6371.0;
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (lon2 - lon1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// Conversion into a region, so functions can accept either a typed region or
+/// a string, which gets validated
+pub trait IntoRegion {
+    /// Converts the value into a region
+    fn into_region(self) -> Result<AwsRegionId, crate::Error>;
+}
+
+impl IntoRegion for AwsRegionId {
+    fn into_region(self) -> Result<AwsRegionId, crate::Error> {
+        Ok(self)
+    }
+}
+
+impl IntoRegion for &str {
+    fn into_region(self) -> Result<AwsRegionId, crate::Error> {
+        AwsRegionId::try_from(self)
+    }
+}
+
+impl IntoRegion for String {
+    fn into_region(self) -> Result<AwsRegionId, crate::Error> {
+        AwsRegionId::try_from(self)
+    }
+}
+
+impl IntoRegion for &String {
+    fn into_region(self) -> Result<AwsRegionId, crate::Error> {
+        AwsRegionId::try_from(self)
+    }
+}
+
+impl AsRef<str> for AwsRegionId {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl TryFrom<String> for AwsRegionId {
+    type Error = crate::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl TryFrom<&String> for AwsRegionId {
     type Error = crate::Error;
 
-    fn try_from(s: &str) -> Result<Self, Self::Error> {
-        match s {
-            "af-south-1" => Ok(AwsRegionId::AfSouth1),
-            "ap-east-1" => Ok(AwsRegionId::ApEast1),
-            "ap-northeast-1" => Ok(AwsRegionId::ApNortheast1),
-            "ap-northeast-2" => Ok(AwsRegionId::ApNortheast2),
-            "ap-northeast-3" => Ok(AwsRegionId::ApNortheast3),
-            "ap-south-1" => Ok(AwsRegionId::ApSouth1),
-            "ap-south-2" => Ok(AwsRegionId::ApSouth2),
-            "ap-southeast-1" => Ok(AwsRegionId::ApSoutheast1),
-            "ap-southeast-2" => Ok(AwsRegionId::ApSoutheast2),
-            "ap-southeast-3" => Ok(AwsRegionId::ApSoutheast3),
-            "ap-southeast-4" => Ok(AwsRegionId::ApSoutheast4),
-            "ca-central-1" => Ok(AwsRegionId::CaCentral1),
-            "ca-west-1" => Ok(AwsRegionId::CaWest1),
-            "eu-central-1" => Ok(AwsRegionId::EuCentral1),
-            "eu-central-2" => Ok(AwsRegionId::EuCentral2),
-            "eu-north-1" => Ok(AwsRegionId::EuNorth1),
-            "eu-south-1" => Ok(AwsRegionId::EuSouth1),
-            "eu-south-2" => Ok(AwsRegionId::EuSouth2),
-            "eu-west-1" => Ok(AwsRegionId::EuWest1),
-            "eu-west-2" => Ok(AwsRegionId::EuWest2),
-            "eu-west-3" => Ok(AwsRegionId::EuWest3),
-            "il-central-1" => Ok(AwsRegionId::IlCentral1),
-            "me-central-1" => Ok(AwsRegionId::MeCentral1),
-            "me-south-1" => Ok(AwsRegionId::MeSouth1),
-            "sa-east-1" => Ok(AwsRegionId::SaEast1),
-            "us-east-1" => Ok(AwsRegionId::UsEast1),
-            "us-east-2" => Ok(AwsRegionId::UsEast2),
-            "us-west-1" => Ok(AwsRegionId::UsWest1),
-            "us-west-2" => Ok(AwsRegionId::UsWest2),
-            _ => Err(RegionError(s.into()).into()),
-        }
-    }
-}
-
-impl From<AwsRegionId> for &'static str {
-    fn from(region: AwsRegionId) -> Self {
-        match region {
-            AwsRegionId::AfSouth1 => "af-south-1",
-            AwsRegionId::ApEast1 => "ap-east-1",
-            AwsRegionId::ApNortheast1 => "ap-northeast-1",
-            AwsRegionId::ApNortheast2 => "ap-northeast-2",
-            AwsRegionId::ApNortheast3 => "ap-northeast-3",
-            AwsRegionId::ApSouth1 => "ap-south-1",
-            AwsRegionId::ApSouth2 => "ap-south-2",
-            AwsRegionId::ApSoutheast1 => "ap-southeast-1",
-            AwsRegionId::ApSoutheast2 => "ap-southeast-2",
-            AwsRegionId::ApSoutheast3 => "ap-southeast-3",
-            AwsRegionId::ApSoutheast4 => "ap-southeast-4",
-            AwsRegionId::CaCentral1 => "ca-central-1",
-            AwsRegionId::CaWest1 => "ca-west-1",
-            AwsRegionId::EuCentral1 => "eu-central-1",
-            AwsRegionId::EuCentral2 => "eu-central-2",
-            AwsRegionId::EuNorth1 => "eu-north-1",
-            AwsRegionId::EuSouth1 => "eu-south-1",
-            AwsRegionId::EuSouth2 => "eu-south-2",
-            AwsRegionId::EuWest1 => "eu-west-1",
-            AwsRegionId::EuWest2 => "eu-west-2",
-            AwsRegionId::EuWest3 => "eu-west-3",
-            AwsRegionId::IlCentral1 => "il-central-1",
-            AwsRegionId::MeCentral1 => "me-central-1",
-            AwsRegionId::MeSouth1 => "me-south-1",
-            AwsRegionId::SaEast1 => "sa-east-1",
-            AwsRegionId::UsEast1 => "us-east-1",
-            AwsRegionId::UsEast2 => "us-east-2",
-            AwsRegionId::UsWest1 => "us-west-1",
-            AwsRegionId::UsWest2 => "us-west-2",
-        }
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl FromStr for AwsRegionId {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for AwsRegionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl PartialEq<str> for AwsRegionId {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref() == other
+    }
+}
+
+impl PartialEq<&str> for AwsRegionId {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_ref() == *other
+    }
+}
+
+impl PartialEq<String> for AwsRegionId {
+    fn eq(&self, other: &String) -> bool {
+        self.as_ref() == other
+    }
+}
+
+impl PartialEq<AwsRegionId> for str {
+    fn eq(&self, other: &AwsRegionId) -> bool {
+        self == other.as_ref()
+    }
+}
+
+impl PartialEq<AwsRegionId> for &str {
+    fn eq(&self, other: &AwsRegionId) -> bool {
+        *self == other.as_ref()
+    }
+}
+
+impl PartialEq<AwsRegionId> for String {
+    fn eq(&self, other: &AwsRegionId) -> bool {
+        self == other.as_ref()
+    }
+}
+
+impl From<AwsRegionId> for alloc::borrow::Cow<'static, [u8]> {
+    fn from(region: AwsRegionId) -> Self {
+        let code: &'static str = region.into();
+        Self::Borrowed(code.as_bytes())
+    }
+}
+
+impl From<AwsRegionId> for String {
+    fn from(value: AwsRegionId) -> Self {
+        value.to_string()
+    }
+}
+
+/// Valid regions are deserialized without allocating: the visitor matches the
+/// borrowed or transient string directly, and the result refers to the
+/// static region data. The `serde-lenient` feature allocates to lowercase the
+/// input.
+///
+/// Self-describing formats, like JSON, also accept the index of
+/// [`crate::Compact`] regions.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AwsRegionId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // Self-describing formats report the actual type, so an integer is
+        // read as the compact index and other values get a precise error.
+        // Compact formats may not support `deserialize_any`, so they're asked
+        // for a string.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(RegionVisitor)
+        } else {
+            deserializer.deserialize_str(RegionVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct RegionVisitor;
+
+#[cfg(feature = "serde")]
+impl serde::de::Visitor<'_> for RegionVisitor {
+    type Value = AwsRegionId;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string AWS region id or its index")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        AwsRegionId::try_from(&*crate::lenient_input(v)).map_err(E::custom)
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(E::custom(format!("unknown region index: {v}"))),
+        }
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        RegionIndexVisitor.visit_u64(v)
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Err(E::custom(
+            "expected a string AWS region id or its index, found a float",
+        ))
+    }
+}
+
+#[cfg(feature = "serde")]
+struct RegionIndexVisitor;
+
+#[cfg(feature = "serde")]
+impl serde::de::Visitor<'_> for RegionIndexVisitor {
+    type Value = AwsRegionId;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an AWS region index")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        u8::try_from(v)
+            .ok()
+            .and_then(AwsRegionId::from_compact_index)
+            .ok_or_else(|| E::custom(format!("unknown region index: {v}")))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for AwsRegionId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_ref())
+    }
+}
+
+/// Human-readable formats, like JSON, get the region code, compact ones a
+/// stable one byte index
+#[cfg(feature = "serde")]
+impl serde::Serialize for crate::Compact<AwsRegionId> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            self.0.serialize(serializer)
+        } else {
+            serializer.serialize_u8(self.0.compact_index())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for crate::Compact<AwsRegionId> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            AwsRegionId::deserialize(deserializer).map(Self)
+        } else {
+            deserializer.deserialize_u8(RegionIndexVisitor).map(Self)
+        }
+    }
+}
+
+/// A region (de)serialized by its human-readable name, e.g.
+/// `"Europe (Frankfurt)"`, for UIs persisting region choices
+///
+/// The region itself is still (de)serialized by its code.
+#[cfg(feature = "serde")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RegionLongName(pub AwsRegionId);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RegionLongName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.0.long_name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RegionLongName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let name = alloc::borrow::Cow::<str>::deserialize(deserializer)?;
+        AwsRegionId::ALL
+            .iter()
+            .find(|r| r.long_name() == name)
+            .map(|&r| Self(r))
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown region name: {name}")))
+    }
+}
+
+/// Region codes are always valid header values, so the conversion is
+/// infallible (`TryFrom` is provided by the blanket implementation)
+#[cfg(feature = "http")]
+impl From<AwsRegionId> for http::HeaderValue {
+    fn from(region: AwsRegionId) -> Self {
+        http::HeaderValue::from_static(region.into())
+    }
+}
+
+/// Conversions with the rusoto `Region` (re-exported as `rusoto_core::Region`)
+///
+/// Both directions are fallible: rusoto lacks the recently launched regions,
+/// and its `Custom` regions have no counterpart here.
+#[cfg(feature = "rusoto")]
+mod rusoto_impl {
+    use super::{AwsRegionId, RegionError};
+    use rusoto_signature::Region;
+
+    impl TryFrom<AwsRegionId> for Region {
+        type Error = crate::Error;
+
+        fn try_from(region: AwsRegionId) -> Result<Self, Self::Error> {
+            region
+                .as_ref()
+                .parse()
+                .map_err(|_| RegionError(region.to_string()).into())
+        }
+    }
+
+    impl TryFrom<Region> for AwsRegionId {
+        type Error = crate::Error;
+
+        fn try_from(region: Region) -> Result<Self, Self::Error> {
+            match region {
+                Region::Custom { name, .. } => Err(RegionError(name).into()),
+                _ => AwsRegionId::try_from(region.name()),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_to_rusoto() {
+            assert_eq!(
+                Region::try_from(AwsRegionId::EuWest1).unwrap(),
+                Region::EuWest1
+            );
+            assert!(Region::try_from(AwsRegionId::ApSouth2).is_err());
+        }
+
+        #[test]
+        fn test_from_rusoto() {
+            assert_eq!(
+                AwsRegionId::try_from(Region::EuWest1).unwrap(),
+                AwsRegionId::EuWest1
+            );
+            assert_eq!(
+                AwsRegionId::try_from(Region::UsGovWest1).unwrap(),
+                AwsRegionId::UsGovWest1
+            );
+            assert_eq!(
+                AwsRegionId::try_from(Region::CnNorth1).unwrap(),
+                AwsRegionId::CnNorth1
+            );
+            let custom = Region::Custom {
+                name: "eu-west-1".into(),
+                endpoint: "http://localhost:8000".into(),
+            };
+            assert!(AwsRegionId::try_from(custom).is_err());
+        }
+    }
+}
+
+#[cfg(feature = "sqlx-postgres")]
+mod sqlx_impl {
+    use super::AwsRegionId;
+    use sqlx::{
+        postgres::{PgTypeInfo, PgValueRef},
+        Postgres, Type,
+    };
+
+    impl Type<Postgres> for AwsRegionId {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for AwsRegionId {
+        fn encode_by_ref(
+            &self,
+            buf: &mut sqlx::postgres::PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Send + Sync>> {
+            <&str as sqlx::Encode<Postgres>>::encode(self.as_ref(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for AwsRegionId {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(AwsRegionId::try_from(s)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_REGIONS: [&str; 38] = [
+        "af-south-1",
+        "ap-east-1",
+        "ap-east-2",
+        "ap-northeast-1",
+        "ap-northeast-2",
+        "ap-northeast-3",
+        "ap-south-1",
+        "ap-south-2",
+        "ap-southeast-1",
+        "ap-southeast-2",
+        "ap-southeast-3",
+        "ap-southeast-4",
+        "ap-southeast-5",
+        "ap-southeast-6",
+        "ap-southeast-7",
+        "ca-central-1",
+        "ca-west-1",
+        "cn-north-1",
+        "cn-northwest-1",
+        "eu-central-1",
+        "eu-central-2",
+        "eu-north-1",
+        "eu-south-1",
+        "eu-south-2",
+        "eu-west-1",
+        "eu-west-2",
+        "eu-west-3",
+        "il-central-1",
+        "me-central-1",
+        "me-south-1",
+        "mx-central-1",
+        "sa-east-1",
+        "us-east-1",
+        "us-east-2",
+        "us-gov-east-1",
+        "us-gov-west-1",
+        "us-west-1",
+        "us-west-2",
+    ];
+
+    #[test]
+    fn test_all_regions_covered() {
+        let all_regions = ALL_REGIONS;
+        assert_eq!(all_regions.len(), 38);
+
+        for region_str in all_regions {
+            let region = AwsRegionId::try_from(region_str).unwrap();
+            assert_eq!(region.as_ref(), region_str);
+        }
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(
+            AwsRegionId::try_from("us-east-1").unwrap(),
+            AwsRegionId::try_from("us-east-1").unwrap(),
+        );
+        assert_ne!(
+            AwsRegionId::try_from("us-east-1").unwrap(),
+            AwsRegionId::try_from("eu-west-2").unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_ord_matches_code_order() {
+        let set: std::collections::BTreeSet<_> = [
+            AwsRegionId::UsWest2,
+            AwsRegionId::EuWest1,
+            AwsRegionId::ApSoutheast2,
+            AwsRegionId::UsEast1,
+            AwsRegionId::CaCentral1,
+        ]
+        .into_iter()
+        .collect();
+        let codes: Vec<&str> = set.into_iter().map(Into::into).collect();
+        assert_eq!(
+            codes,
+            [
+                "ap-southeast-2",
+                "ca-central-1",
+                "eu-west-1",
+                "us-east-1",
+                "us-west-2"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_eq_str() {
+        let region = AwsRegionId::UsEast1;
+        let string = "us-east-1".to_string();
+        assert!(region == "us-east-1");
+        assert!(region == *"us-east-1");
+        assert!(region == string);
+        assert!(region != "us-east-2");
+    }
+
+    #[test]
+    fn test_str_eq() {
+        let region = AwsRegionId::UsEast1;
+        let string = "us-east-1".to_string();
+        assert!("us-east-1" == region);
+        assert!(*"us-east-1" == region);
+        assert!(string == region);
+        assert!("us-east-2" != region);
+    }
+
+    #[test]
+    fn test_valid_regions() {
+        assert_eq!(
+            AwsRegionId::try_from("us-east-1").unwrap(),
+            AwsRegionId::UsEast1
+        );
+        assert_eq!(
+            AwsRegionId::try_from("eu-west-2").unwrap(),
+            AwsRegionId::EuWest2
+        );
+    }
+
+    #[test]
+    fn test_invalid_region() {
+        assert!(AwsRegionId::try_from("invalid-region").is_err());
+    }
+
+    #[test]
+    fn test_try_from_qualified() {
+        assert_eq!(
+            AwsRegionId::try_from_qualified("aws:us-east-1").unwrap(),
+            AwsRegionId::UsEast1
+        );
+        assert_eq!(
+            AwsRegionId::try_from_qualified("us-east-1").unwrap(),
+            AwsRegionId::UsEast1
+        );
+        assert_eq!(
+            AwsRegionId::try_from_qualified("aws-cn:cn-north-1").unwrap(),
+            AwsRegionId::CnNorth1
+        );
+        for (input, message) in [
+            (
+                "aws-cn:us-east-1",
+                "region us-east-1 belongs to the \"aws\" partition, not \"aws-cn\"",
+            ),
+            (
+                "aws:cn-north-1",
+                "region cn-north-1 belongs to the \"aws-cn\" partition, not \"aws\"",
+            ),
+            (
+                "foo:us-east-1",
+                "region us-east-1 belongs to the \"aws\" partition, not \"foo\"",
+            ),
+            (
+                ":us-east-1",
+                "region us-east-1 belongs to the \"aws\" partition, not \"\"",
+            ),
+        ] {
+            let err = AwsRegionId::try_from_qualified(input).unwrap_err();
+            assert!(matches!(err, crate::Error::PartitionMismatch(_)), "{input}");
+            assert_eq!(err.to_string(), message);
+        }
+        assert!(matches!(
+            AwsRegionId::try_from_qualified("aws:us-east-9"),
+            Err(crate::Error::Region(_))
+        ));
+        assert_eq!(AwsPartition::AwsUsGov.as_str(), "aws-us-gov");
+    }
+
+    #[test]
+    fn test_try_from_including_global() {
+        assert_eq!(
+            AwsRegionId::try_from_including_global("aws-global").unwrap(),
+            RegionOrGlobal::Global(AwsPartition::Aws)
+        );
+        assert_eq!(
+            AwsRegionId::try_from_including_global("aws-cn-global").unwrap(),
+            RegionOrGlobal::Global(AwsPartition::AwsCn)
+        );
+        assert_eq!(
+            AwsRegionId::try_from_including_global("aws-us-gov-global").unwrap(),
+            RegionOrGlobal::Global(AwsPartition::AwsUsGov)
+        );
+        assert_eq!(
+            AwsRegionId::try_from_including_global("eu-west-1").unwrap(),
+            RegionOrGlobal::Region(AwsRegionId::EuWest1)
+        );
+        assert!(AwsRegionId::try_from_including_global("invalid-region").is_err());
+        assert!(AwsRegionId::try_from("aws-global").is_err());
+    }
+
+    #[test]
+    fn test_geography() {
+        assert_eq!(AwsRegionId::AfSouth1.geography(), Geography::Africa);
+        assert_eq!(AwsRegionId::ApSouth2.geography(), Geography::AsiaPacific);
+        assert_eq!(AwsRegionId::IlCentral1.geography(), Geography::MiddleEast);
+        assert_eq!(AwsRegionId::CaWest1.geography(), Geography::NorthAmerica);
+        assert_eq!(AwsRegionId::MxCentral1.geography(), Geography::NorthAmerica);
+        assert_eq!(AwsRegionId::SaEast1.geography(), Geography::SouthAmerica);
+    }
+
+    #[test]
+    fn test_same_geography() {
+        assert!(AwsRegionId::EuWest1.same_geography(&AwsRegionId::EuCentral1));
+        assert!(!AwsRegionId::EuWest1.same_geography(&AwsRegionId::UsEast1));
+    }
+
+    #[test]
+    fn test_long_name() {
+        assert_eq!(AwsRegionId::EuCentral1.long_name(), "Europe (Frankfurt)");
+        assert_eq!(
+            AwsRegionId::SaEast1.long_name(),
+            "South America (São Paulo)"
+        );
+    }
+
+    #[test]
+    fn test_id_and_name() {
+        assert_eq!(
+            AwsRegionId::UsEast1.id_and_name(),
+            ("us-east-1", "US East (N. Virginia)")
+        );
+    }
+
+    #[test]
+    fn test_country_code() {
+        assert_eq!(AwsRegionId::EuCentral1.country_code(), "DE");
+        assert_eq!(AwsRegionId::ApEast1.country_code(), "HK");
+        assert_eq!(AwsRegionId::UsGovWest1.country_code(), "US");
+        for region in AwsRegionId::ALL {
+            let code = region.country_code();
+            assert!(
+                code.len() == 2 && code.bytes().all(|b| b.is_ascii_uppercase()),
+                "{region}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_search() {
+        assert_eq!(search("frank"), [AwsRegionId::EuCentral1]);
+        assert_eq!(
+            search("US"),
+            [
+                AwsRegionId::UsEast1,
+                AwsRegionId::UsEast2,
+                AwsRegionId::UsGovEast1,
+                AwsRegionId::UsGovWest1,
+                AwsRegionId::UsWest1,
+                AwsRegionId::UsWest2,
+            ]
+        );
+        assert_eq!(
+            search("govcloud"),
+            [AwsRegionId::UsGovEast1, AwsRegionId::UsGovWest1]
+        );
+        assert_eq!(search("eu-west-2")[0], AwsRegionId::EuWest2);
+        assert_eq!(
+            search("jp"),
+            [AwsRegionId::ApNortheast1, AwsRegionId::ApNortheast3]
+        );
+        // Hyderabad contains "de", but the country code match goes first
+        assert_eq!(
+            search("DE"),
+            [AwsRegionId::EuCentral1, AwsRegionId::ApSouth2]
+        );
+        assert_eq!(search("são")[0], AwsRegionId::SaEast1);
+        assert_eq!(search(" ").len(), AwsRegionId::ALL.len());
+        assert!(search("atlantis").is_empty());
+    }
+
+    #[test]
+    fn test_validate_regions() {
+        assert_eq!(
+            validate_regions(["eu-west-1", "us-east-1"]).unwrap(),
+            [AwsRegionId::EuWest1, AwsRegionId::UsEast1]
+        );
+        assert!(validate_regions([]).unwrap().is_empty());
+
+        let errors =
+            validate_regions(["eu-west-1", "eu-west-42", "us-east-1", "", "mars-1"]).unwrap_err();
+        let indices: Vec<_> = errors.iter().map(|(i, _)| *i).collect();
+        assert_eq!(indices, [1, 3, 4]);
+        assert!(errors
+            .iter()
+            .all(|(_, e)| matches!(e, crate::Error::Region(_))));
+        assert_eq!(errors[0].1.to_string(), "Unknown region: eu-west-42");
     }
-}
 
-impl AsRef<str> for AwsRegionId {
-    fn as_ref(&self) -> &str {
-        (*self).into()
+    #[test]
+    fn test_cmp_by_name() {
+        let mut regions = [
+            AwsRegionId::UsWest2,
+            AwsRegionId::ApSoutheast1,
+            AwsRegionId::EuWest1,
+            AwsRegionId::ApSouth1,
+            AwsRegionId::UsEast1,
+        ];
+        regions.sort_by(AwsRegionId::cmp_by_name);
+        let names: Vec<&str> = regions.iter().map(AsRef::as_ref).collect();
+        assert_eq!(
+            names,
+            [
+                "ap-south-1",
+                "ap-southeast-1",
+                "eu-west-1",
+                "us-east-1",
+                "us-west-2"
+            ]
+        );
     }
-}
 
-impl TryFrom<String> for AwsRegionId {
-    type Error = crate::Error;
+    #[test]
+    fn test_capabilities() {
+        let caps = AwsRegionId::UsEast1.capabilities();
+        assert!(caps.contains(RegionCapabilities::FIPS));
+        assert!(caps.contains(RegionCapabilities::DUALSTACK));
+        assert!(caps.contains(RegionCapabilities::PARTITION_AWS));
+        assert!(!caps.contains(RegionCapabilities::OPT_IN));
+        assert!(!caps.contains(RegionCapabilities::PARTITION_AWS_US_GOV));
 
-    fn try_from(s: String) -> Result<Self, Self::Error> {
-        Self::try_from(s.as_str())
+        let caps = AwsRegionId::AfSouth1.capabilities();
+        assert!(caps.contains(RegionCapabilities::OPT_IN | RegionCapabilities::PARTITION_AWS));
+        assert!(caps.contains(RegionCapabilities::DUALSTACK));
+        assert!(!caps.contains(RegionCapabilities::FIPS));
+
+        let caps = AwsRegionId::UsGovWest1.capabilities();
+        assert!(caps.contains(
+            RegionCapabilities::FIPS
+                | RegionCapabilities::DUALSTACK
+                | RegionCapabilities::PARTITION_AWS_US_GOV
+        ));
+        assert!(!caps.contains(RegionCapabilities::PARTITION_AWS));
+
+        let caps = AwsRegionId::CnNorthwest1.capabilities();
+        assert!(caps.contains(RegionCapabilities::PARTITION_AWS_CN));
+        assert!(!caps.contains(RegionCapabilities::PARTITION_AWS));
+        assert!(!caps.contains(RegionCapabilities::FIPS));
+        assert_eq!(RegionCapabilities::default().bits(), 0);
     }
-}
 
-impl TryFrom<&String> for AwsRegionId {
-    type Error = crate::Error;
+    #[test]
+    fn test_sts_endpoint() {
+        assert_eq!(
+            AwsRegionId::EuWest1.sts_endpoint(),
+            "sts.eu-west-1.amazonaws.com"
+        );
+        assert_eq!(
+            AwsRegionId::EuWest1.sts_global_endpoint(),
+            "sts.amazonaws.com"
+        );
+        assert_eq!(
+            endpoint_host("sts", "cn-northwest-1", AwsPartition::AwsCn),
+            "sts.cn-northwest-1.amazonaws.com.cn"
+        );
+    }
 
-    fn try_from(s: &String) -> Result<Self, Self::Error> {
-        Self::try_from(s.as_str())
+    #[test]
+    fn test_data_version() {
+        let parts: Vec<u32> = data_version()
+            .split('-')
+            .map(|p| p.parse().unwrap())
+            .collect();
+        let [year, month, day] = parts[..] else {
+            panic!("expected YYYY-MM-DD, got {DATA_VERSION}");
+        };
+        assert!(year >= 2024);
+        assert!((1..=12).contains(&month));
+        assert!((1..=31).contains(&day));
     }
-}
 
-impl FromStr for AwsRegionId {
-    type Err = crate::Error;
+    #[test]
+    fn test_into_cow_bytes() {
+        use std::borrow::Cow;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::try_from(s)
+        let bytes = Cow::<[u8]>::from(AwsRegionId::EuWest1);
+        assert_eq!(&*bytes, b"eu-west-1");
+        assert!(matches!(bytes, Cow::Borrowed(_)));
     }
-}
 
-impl fmt::Display for AwsRegionId {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(self.as_ref())
+    #[test]
+    fn test_to_uppercase_string() {
+        assert_eq!(AwsRegionId::UsEast1.to_uppercase_string(), "US-EAST-1");
+        assert_eq!(
+            AwsRegionId::ApSoutheast2.to_uppercase_string(),
+            "AP-SOUTHEAST-2"
+        );
     }
-}
 
-impl From<AwsRegionId> for String {
-    fn from(value: AwsRegionId) -> Self {
-        value.to_string()
+    #[test]
+    fn test_from_endpoint() {
+        let endpoint = AwsRegionId::from_endpoint("sqs.eu-west-1.amazonaws.com").unwrap();
+        assert_eq!(endpoint.region(), AwsRegionId::EuWest1);
+        assert!(!endpoint.is_fips());
+
+        let endpoint = AwsRegionId::from_endpoint("ec2-fips.us-east-1.amazonaws.com").unwrap();
+        assert_eq!(endpoint.region(), AwsRegionId::UsEast1);
+        assert!(endpoint.is_fips());
+
+        let endpoint =
+            AwsRegionId::from_endpoint("https://s3-fips.dualstack.us-west-2.amazonaws.com/bucket")
+                .unwrap();
+        assert_eq!(endpoint.region(), AwsRegionId::UsWest2);
+        assert!(endpoint.is_fips());
+
+        let endpoint = AwsRegionId::from_endpoint("ec2.cn-northwest-1.amazonaws.com.cn").unwrap();
+        assert_eq!(endpoint.region(), AwsRegionId::CnNorthwest1);
+        assert!(!endpoint.is_fips());
+
+        let endpoint = AwsRegionId::from_endpoint("ec2-fisma.us-east-2.amazonaws.com:443").unwrap();
+        assert_eq!(endpoint.region(), AwsRegionId::UsEast2);
+        assert!(endpoint.is_fips());
+
+        for input in [
+            "sqs.amazonaws.com",
+            "sqs.eu-west-9.amazonaws.com",
+            "sqs.eu-west-1.example.com",
+            "",
+        ] {
+            assert!(
+                matches!(
+                    AwsRegionId::from_endpoint(input),
+                    Err(crate::Error::Region(_))
+                ),
+                "{input}"
+            );
+        }
     }
-}
 
-#[cfg(feature = "serde")]
-impl<'de> serde::Deserialize<'de> for AwsRegionId {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        AwsRegionId::try_from(s.as_str()).map_err(serde::de::Error::custom)
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_env() {
+        std::env::set_var("AWS_RESOURCE_ID_TEST_REGION", "eu-west-1");
+        assert_eq!(
+            AwsRegionId::from_env("AWS_RESOURCE_ID_TEST_REGION").unwrap(),
+            AwsRegionId::EuWest1
+        );
+
+        std::env::set_var("AWS_RESOURCE_ID_TEST_BAD_REGION", "eu-west-42");
+        assert!(matches!(
+            AwsRegionId::from_env("AWS_RESOURCE_ID_TEST_BAD_REGION"),
+            Err(crate::Error::Region(_))
+        ));
     }
-}
 
-#[cfg(feature = "serde")]
-impl serde::Serialize for AwsRegionId {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        serializer.serialize_str(self.as_ref())
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_env_unset() {
+        let err = AwsRegionId::from_env("AWS_RESOURCE_ID_TEST_UNSET").unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::EnvVar {
+                source: std::env::VarError::NotPresent,
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "failed to read environment variable AWS_RESOURCE_ID_TEST_UNSET: environment variable not found"
+        );
     }
-}
 
-#[cfg(feature = "sqlx-postgres")]
-mod sqlx_impl {
-    use super::AwsRegionId;
-    use sqlx::{
-        postgres::{PgTypeInfo, PgValueRef},
-        Postgres, Type,
-    };
+    #[test]
+    fn test_endpoint_url() {
+        assert_eq!(
+            AwsRegionId::UsEast1.endpoint_url("ec2"),
+            "https://ec2.us-east-1.amazonaws.com"
+        );
+        assert_eq!(AwsRegionId::EuWest1.partition(), AwsPartition::Aws);
+        assert_eq!(
+            AwsRegionId::CnNorth1.endpoint_url("ec2"),
+            "https://ec2.cn-north-1.amazonaws.com.cn"
+        );
+        assert_eq!(
+            endpoint_url("ec2", "us-gov-west-1", AwsPartition::AwsUsGov),
+            "https://ec2.us-gov-west-1.amazonaws.com"
+        );
+    }
 
-    impl Type<Postgres> for AwsRegionId {
-        fn type_info() -> PgTypeInfo {
-            <String as Type<Postgres>>::type_info()
-        }
+    #[test]
+    fn test_arn_region_field() {
+        assert_eq!(AwsRegionId::EuWest1.arn_region_field("iam"), "");
+        assert_eq!(AwsRegionId::EuWest1.arn_region_field("cloudfront"), "");
+        assert_eq!(AwsRegionId::EuWest1.arn_region_field("ec2"), "eu-west-1");
+    }
 
-        fn compatible(ty: &PgTypeInfo) -> bool {
-            <String as Type<Postgres>>::compatible(ty)
-        }
+    #[test]
+    fn test_substitute_into() {
+        assert_eq!(
+            AwsRegionId::EuWest1.substitute_into("arn:aws:sqs:${AWS::Region}:123456789012:queue"),
+            "arn:aws:sqs:eu-west-1:123456789012:queue"
+        );
+        assert_eq!(
+            AwsRegionId::UsEast1.substitute_into("https://s3.{region}.amazonaws.com"),
+            "https://s3.us-east-1.amazonaws.com"
+        );
+        assert_eq!(
+            AwsRegionId::UsEast1.substitute_into("no placeholders"),
+            "no placeholders"
+        );
     }
 
-    impl sqlx::Encode<'_, Postgres> for AwsRegionId {
-        fn encode_by_ref(
-            &self,
-            buf: &mut sqlx::postgres::PgArgumentBuffer,
-        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Send + Sync>> {
-            <&str as sqlx::Encode<Postgres>>::encode(self.as_ref(), buf)
+    #[test]
+    fn test_is_retiring() {
+        for region_str in ALL_REGIONS {
+            assert!(!AwsRegionId::try_from(region_str).unwrap().is_retiring());
         }
     }
 
-    impl<'r> sqlx::Decode<'r, Postgres> for AwsRegionId {
-        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
-            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
-            Ok(AwsRegionId::try_from(s)?)
+    #[test]
+    fn test_all() {
+        assert!(AwsRegionId::ALL.windows(2).all(|w| w[0] < w[1]));
+        for region_str in ALL_REGIONS {
+            assert!(AwsRegionId::ALL.contains(&region_str.parse().unwrap()));
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_generated_conversions() {
+        assert_eq!(AwsRegionId::ALL.len(), ALL_REGIONS.len());
+        for &region in AwsRegionId::ALL {
+            let code: &'static str = region.into();
+            assert_eq!(AwsRegionId::try_from(code).unwrap(), region);
+        }
+        assert_eq!(AwsRegionId::EuWest1.partition(), AwsPartition::Aws);
+        assert_eq!(AwsRegionId::UsGovWest1.partition(), AwsPartition::AwsUsGov);
+        assert_eq!(AwsRegionId::CnNorth1.partition(), AwsPartition::AwsCn);
+        assert_eq!(AwsRegionId::ApSouth1.long_name(), "Asia Pacific (Mumbai)");
+        assert_eq!(
+            AwsRegionId::SaEast1.long_name(),
+            "South America (São Paulo)"
+        );
+    }
 
     #[test]
-    fn test_all_regions_covered() {
-        let all_regions = [
-            "af-south-1",
-            "ap-east-1",
-            "ap-northeast-1",
-            "ap-northeast-2",
-            "ap-northeast-3",
-            "ap-south-1",
-            "ap-south-2",
-            "ap-southeast-1",
-            "ap-southeast-2",
-            "ap-southeast-3",
-            "ap-southeast-4",
-            "ca-central-1",
-            "ca-west-1",
-            "eu-central-1",
-            "eu-central-2",
-            "eu-north-1",
-            "eu-south-1",
-            "eu-south-2",
-            "eu-west-1",
-            "eu-west-2",
-            "eu-west-3",
-            "il-central-1",
-            "me-central-1",
-            "me-south-1",
-            "sa-east-1",
-            "us-east-1",
-            "us-east-2",
-            "us-west-1",
-            "us-west-2",
-        ];
-        assert_eq!(all_regions.len(), 29);
+    fn test_is_opt_in() {
+        let opt_in: Vec<&str> = ALL_REGIONS
+            .into_iter()
+            .filter(|r| AwsRegionId::try_from(*r).unwrap().is_opt_in())
+            .collect();
+        assert_eq!(
+            opt_in,
+            [
+                "af-south-1",
+                "ap-east-1",
+                "ap-east-2",
+                "ap-south-2",
+                "ap-southeast-3",
+                "ap-southeast-4",
+                "ap-southeast-5",
+                "ap-southeast-6",
+                "ap-southeast-7",
+                "ca-west-1",
+                "eu-central-2",
+                "eu-south-1",
+                "eu-south-2",
+                "il-central-1",
+                "me-central-1",
+                "me-south-1",
+                "mx-central-1",
+            ]
+        );
+    }
 
-        for region_str in all_regions {
-            let region = AwsRegionId::try_from(region_str).unwrap();
-            assert_eq!(region.as_ref(), region_str);
-        }
+    #[test]
+    fn test_timezone_name() {
+        assert_eq!(AwsRegionId::UsEast1.timezone_name(), "America/New_York");
+        assert_eq!(AwsRegionId::EuCentral2.timezone_name(), "Europe/Zurich");
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_eq() {
+    fn test_nearest_to() {
+        // Mainz, near Frankfurt
+        assert_eq!(AwsRegionId::nearest_to(50.0, 8.27), AwsRegionId::EuCentral1);
+        // Richmond, Virginia
+        assert_eq!(AwsRegionId::nearest_to(37.54, -77.44), AwsRegionId::UsEast1);
+        // Portland, Oregon, next to the GovCloud (US-West) data centers
         assert_eq!(
-            AwsRegionId::try_from("us-east-1").unwrap(),
-            AwsRegionId::try_from("us-east-1").unwrap(),
+            AwsRegionId::nearest_to(45.52, -122.68),
+            AwsRegionId::UsWest2
         );
-        assert_ne!(
-            AwsRegionId::try_from("us-east-1").unwrap(),
-            AwsRegionId::try_from("eu-west-2").unwrap(),
+        // Perth, far from anything but closest to Melbourne
+        assert_eq!(
+            AwsRegionId::nearest_to(-31.95, 115.86),
+            AwsRegionId::ApSoutheast4
         );
     }
 
     #[test]
-    fn test_valid_regions() {
+    fn test_supports_fips() {
+        assert!(AwsRegionId::UsEast1.supports_fips());
+        assert!(AwsRegionId::CaCentral1.supports_fips());
+        assert!(AwsRegionId::UsGovEast1.supports_fips());
+        assert!(AwsRegionId::UsGovWest1.supports_fips());
+        assert!(!AwsRegionId::EuWest1.supports_fips());
+        assert!(!AwsRegionId::ApSoutheast2.supports_fips());
+    }
+
+    #[test]
+    fn test_supports_dualstack() {
+        assert!(AwsRegionId::EuWest1.supports_dualstack());
+        assert!(AwsRegionId::UsGovWest1.supports_dualstack());
+    }
+
+    #[test]
+    fn test_into_region() {
+        fn needs_region(r: impl IntoRegion) -> Result<AwsRegionId, crate::Error> {
+            r.into_region()
+        }
+
         assert_eq!(
-            AwsRegionId::try_from("us-east-1").unwrap(),
-            AwsRegionId::UsEast1
+            needs_region(AwsRegionId::EuWest1).unwrap(),
+            AwsRegionId::EuWest1
         );
+        assert_eq!(needs_region("eu-west-1").unwrap(), AwsRegionId::EuWest1);
         assert_eq!(
-            AwsRegionId::try_from("eu-west-2").unwrap(),
-            AwsRegionId::EuWest2
+            needs_region("eu-west-1".to_string()).unwrap(),
+            AwsRegionId::EuWest1
         );
+        assert!(needs_region("invalid-region").is_err());
     }
 
     #[test]
-    fn test_invalid_region() {
-        assert!(AwsRegionId::try_from("invalid-region").is_err());
+    fn test_is_global_service() {
+        assert!(is_global_service("iam"));
+        assert!(is_global_service("cloudfront"));
+        assert!(!is_global_service("ec2"));
     }
 
     #[test]
@@ -377,6 +1973,32 @@ mod tests {
     }
 }
 
+#[cfg(feature = "http")]
+#[cfg(test)]
+mod http_tests {
+    use super::*;
+
+    #[test]
+    fn test_into_header_value() {
+        let value = http::HeaderValue::from(AwsRegionId::EuWest1);
+        assert_eq!(value, "eu-west-1");
+    }
+}
+
+#[cfg(feature = "url")]
+#[cfg(test)]
+mod url_tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_url_parsed() {
+        let url = AwsRegionId::EuWest1.endpoint_url_parsed("sqs").unwrap();
+        assert_eq!(url.host_str(), Some("sqs.eu-west-1.amazonaws.com"));
+        assert_eq!(url.scheme(), "https");
+        assert!(AwsRegionId::EuWest1.endpoint_url_parsed("no such").is_err());
+    }
+}
+
 #[cfg(feature = "serde")]
 #[cfg(test)]
 mod serde_tests {
@@ -394,6 +2016,205 @@ mod serde_tests {
         let deserialized: AwsRegionId = serde_json::from_str("\"eu-west-1\"").unwrap();
         assert_eq!(deserialized, AwsRegionId::EuWest1);
     }
+
+    #[test]
+    fn test_serde_compact() {
+        use crate::Compact;
+
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&Compact(AwsRegionId::EuWest1), &mut cbor).unwrap();
+        // Small integers fit into the CBOR header byte
+        assert_eq!(cbor, [18]);
+        for &region in AwsRegionId::ALL {
+            let mut cbor = Vec::new();
+            ciborium::into_writer(&Compact(region), &mut cbor).unwrap();
+            assert_eq!(
+                ciborium::from_reader::<Compact<AwsRegionId>, _>(&cbor[..]).unwrap(),
+                Compact(region)
+            );
+        }
+        assert!(ciborium::from_reader::<Compact<AwsRegionId>, _>(&[0x18, 200][..]).is_err());
+        assert_eq!(
+            serde_json::to_string(&Compact(AwsRegionId::EuWest1)).unwrap(),
+            "\"eu-west-1\""
+        );
+    }
+
+    #[test]
+    fn test_serde_binary_string_form() {
+        // Binary formats store the region code, as before the compact form
+        // was added, so the previously persisted data stays readable
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&AwsRegionId::EuWest1, &mut cbor).unwrap();
+        let mut string_cbor = Vec::new();
+        ciborium::into_writer(&"eu-west-1", &mut string_cbor).unwrap();
+        assert_eq!(cbor, string_cbor);
+        assert_eq!(
+            ciborium::from_reader::<AwsRegionId, _>(&cbor[..]).unwrap(),
+            AwsRegionId::EuWest1
+        );
+    }
+
+    #[test]
+    fn test_compact_index_unique() {
+        let mut indexes: Vec<u8> = AwsRegionId::ALL
+            .iter()
+            .map(AwsRegionId::compact_index)
+            .collect();
+        indexes.sort_unstable();
+        indexes.dedup();
+        assert_eq!(indexes.len(), AwsRegionId::ALL.len());
+    }
+
+    #[test]
+    fn test_serialize_error() {
+        let err = AwsRegionId::try_from("invalid-region").unwrap_err();
+        assert_eq!(
+            serde_json::to_value(&err).unwrap(),
+            serde_json::json!({
+                "kind": "unknown_region",
+                "input": "invalid-region",
+                "message": "Unknown region: invalid-region",
+            })
+        );
+
+        let err = AwsRegionId::try_from_qualified("aws-cn:us-east-1").unwrap_err();
+        assert_eq!(
+            serde_json::to_value(&err).unwrap(),
+            serde_json::json!({
+                "kind": "partition_mismatch",
+                "input": "aws-cn:us-east-1",
+                "message": "region us-east-1 belongs to the \"aws\" partition, not \"aws-cn\"",
+            })
+        );
+    }
+
+    #[test]
+    fn test_catalog() {
+        let json = serde_json::to_value(RegionCatalog::default()).unwrap();
+        assert_eq!(json["data_version"], DATA_VERSION);
+        let regions = json["regions"].as_array().unwrap();
+        assert_eq!(regions.len(), AwsRegionId::ALL.len());
+        for (json, region) in regions.iter().zip(AwsRegionId::ALL) {
+            assert_eq!(json["id"], region.as_ref());
+        }
+        assert_eq!(
+            json["regions"][0],
+            serde_json::json!({
+                "id": "af-south-1",
+                "name": "Africa (Cape Town)",
+                "partition": "aws",
+                "geography": "africa",
+                "opt_in": true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_region_long_name() {
+        let json = serde_json::to_string(&RegionLongName(AwsRegionId::EuCentral1)).unwrap();
+        assert_eq!(json, "\"Europe (Frankfurt)\"");
+        let name: RegionLongName = serde_json::from_str(&json).unwrap();
+        assert_eq!(name.0, AwsRegionId::EuCentral1);
+
+        for &region in AwsRegionId::ALL {
+            let json = serde_json::to_string(&RegionLongName(region)).unwrap();
+            assert_eq!(
+                serde_json::from_str::<RegionLongName>(&json).unwrap().0,
+                region
+            );
+        }
+
+        let err = serde_json::from_str::<RegionLongName>("\"eu-central-1\"").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("unknown region name: eu-central-1"));
+    }
+
+    #[test]
+    fn test_deserialize_number() {
+        assert_eq!(
+            serde_json::from_str::<AwsRegionId>("18").unwrap(),
+            AwsRegionId::EuWest1
+        );
+        for (json, message) in [
+            ("255", "unknown region index: 255 at line 1 column 3"),
+            ("256", "unknown region index: 256 at line 1 column 3"),
+            ("-1", "unknown region index: -1 at line 1 column 2"),
+            (
+                "1.5",
+                "expected a string AWS region id or its index, found a float at line 1 column 3",
+            ),
+        ] {
+            let err = serde_json::from_str::<AwsRegionId>(json).unwrap_err();
+            assert_eq!(err.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn test_deserialize_json_string_and_cbor_index() {
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&18u8, &mut cbor).unwrap();
+        assert_eq!(
+            ciborium::from_reader::<crate::Compact<AwsRegionId>, _>(&cbor[..])
+                .unwrap()
+                .0,
+            AwsRegionId::EuWest1
+        );
+        assert_eq!(
+            serde_json::from_str::<crate::Compact<AwsRegionId>>("\"eu-west-1\"")
+                .unwrap()
+                .0,
+            AwsRegionId::EuWest1
+        );
+    }
+
+    #[test]
+    fn test_deserialize_non_string() {
+        let err = serde_json::from_str::<AwsRegionId>("true").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid type: boolean `true`, expected a string AWS region id or its index at line 1 column 4"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_compact_format() {
+        use serde::{de::value::StrDeserializer, Deserialize};
+
+        struct Compact<'a>(StrDeserializer<'a, serde::de::value::Error>);
+
+        impl<'de> serde::Deserializer<'de> for Compact<'de> {
+            type Error = serde::de::value::Error;
+
+            fn is_human_readable(&self) -> bool {
+                false
+            }
+
+            fn deserialize_any<V>(self, _: V) -> Result<V::Value, Self::Error>
+            where
+                V: serde::de::Visitor<'de>,
+            {
+                Err(serde::de::Error::custom("deserialize_any isn't supported"))
+            }
+
+            fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: serde::de::Visitor<'de>,
+            {
+                self.0.deserialize_str(visitor)
+            }
+
+            serde::forward_to_deserialize_any! {
+                bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char string
+                bytes byte_buf option unit unit_struct newtype_struct seq tuple
+                tuple_struct map struct enum identifier ignored_any
+            }
+        }
+
+        let de = Compact(StrDeserializer::new("eu-west-1"));
+        assert_eq!(AwsRegionId::deserialize(de).unwrap(), AwsRegionId::EuWest1);
+    }
 }
 
 #[cfg(feature = "sqlx-postgres")]
@@ -450,3 +2271,24 @@ mod sqlx_tests {
         Ok(())
     }
 }
+
+#[cfg(feature = "chrono-tz")]
+#[cfg(test)]
+mod chrono_tz_tests {
+    use super::*;
+
+    #[test]
+    fn test_timezone() {
+        assert_eq!(
+            AwsRegionId::UsWest2.timezone(),
+            chrono_tz::America::Los_Angeles
+        );
+        assert_eq!(
+            AwsRegionId::ApSoutheast2.timezone(),
+            chrono_tz::Australia::Sydney
+        );
+        for region in AwsRegionId::ALL {
+            assert_eq!(region.timezone().name(), region.timezone_name());
+        }
+    }
+}
@@ -0,0 +1,604 @@
+//! # Region Scoped IDs
+//!
+//! Resource ids combined with their region, e.g.
+//! `us-east-1/i-1234567890abcdef0`, a compact representation for multi-region
+//! inventories, EBS snapshot references, region and account pairs, or
+//! resources shared from another account.
+use crate::{Arn, AwsAccountId, AwsRegionId, AwsSnapshotId};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+/// Error encountered when parsing a region scoped id without a `/` separator
+#[derive(Debug, thiserror::Error)]
+#[error("missing \"/\" between the region and the id in \"{0}\"")]
+pub struct ScopedIdError(String);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ScopedIdError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serialize_error(
+            serializer,
+            "ScopedIdError",
+            "missing_separator",
+            Some(self.0.as_str()),
+            self,
+        )
+    }
+}
+
+/// Error encountered when parsing a malformed snapshot reference
+#[derive(Debug, thiserror::Error)]
+#[error("invalid snapshot reference \"{0}\", expected \"arn:{{partition}}:ec2:{{region}}:{{account}}:snapshot/{{id}}\" or \"{{region}}:{{id}}\"")]
+pub struct SnapshotRefError(String);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SnapshotRefError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serialize_error(
+            serializer,
+            "SnapshotRefError",
+            "invalid_snapshot_ref",
+            Some(self.0.as_str()),
+            self,
+        )
+    }
+}
+
+/// Error encountered when parsing a malformed region and account pair
+#[derive(Debug, thiserror::Error)]
+#[error("invalid region and account \"{0}\", expected \"{{region}}:{{account}}\"")]
+pub struct RegionAccountError(String);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RegionAccountError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serialize_error(
+            serializer,
+            "RegionAccountError",
+            "invalid_region_account",
+            Some(self.0.as_str()),
+            self,
+        )
+    }
+}
+
+/// Error encountered when parsing a malformed shared resource reference
+#[derive(Debug, thiserror::Error)]
+#[error("invalid shared resource reference \"{0}\", expected \"{{account}}/{{id}}\"")]
+pub struct SharedRefError(String);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SharedRefError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serialize_error(
+            serializer,
+            "SharedRefError",
+            "invalid_shared_ref",
+            Some(self.0.as_str()),
+            self,
+        )
+    }
+}
+
+/// A resource id together with its region, formatted as `{region}/{id}`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RegionScopedId<T> {
+    region: AwsRegionId,
+    id: T,
+}
+
+impl<T> RegionScopedId<T> {
+    /// Creates a region scoped id
+    #[must_use]
+    pub fn new(region: AwsRegionId, id: T) -> Self {
+        Self { region, id }
+    }
+
+    /// The region
+    #[must_use]
+    pub fn region(&self) -> AwsRegionId {
+        self.region
+    }
+
+    /// The resource id
+    #[must_use]
+    pub fn id(&self) -> &T {
+        &self.id
+    }
+}
+
+impl<T> TryFrom<&str> for RegionScopedId<T>
+where
+    T: FromStr<Err = crate::Error>,
+{
+    type Error = crate::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (region, id) = s.split_once('/').ok_or_else(|| ScopedIdError(s.into()))?;
+        Ok(Self::new(region.parse()?, id.parse()?))
+    }
+}
+
+impl<T> TryFrom<String> for RegionScopedId<T>
+where
+    T: FromStr<Err = crate::Error>,
+{
+    type Error = crate::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl<T> TryFrom<&String> for RegionScopedId<T>
+where
+    T: FromStr<Err = crate::Error>,
+{
+    type Error = crate::Error;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl<T> FromStr for RegionScopedId<T>
+where
+    T: FromStr<Err = crate::Error>,
+{
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for RegionScopedId<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.region, self.id)
+    }
+}
+
+impl<T: fmt::Display> From<RegionScopedId<T>> for String {
+    fn from(value: RegionScopedId<T>) -> Self {
+        value.to_string()
+    }
+}
+
+/// A reference to an EBS snapshot in a region, e.g. the source of a snapshot
+/// copy
+///
+/// Parsed from either an [`Arn`] like
+/// `arn:{partition}:ec2:{region}:{account}:snapshot/{id}` or a `{region}:{id}`
+/// shorthand, and formatted as the latter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SnapshotRef {
+    region: AwsRegionId,
+    id: AwsSnapshotId,
+}
+
+impl SnapshotRef {
+    /// Creates a snapshot reference
+    #[must_use]
+    pub fn new(region: AwsRegionId, id: AwsSnapshotId) -> Self {
+        Self { region, id }
+    }
+
+    /// The snapshot region
+    #[must_use]
+    pub fn region(&self) -> AwsRegionId {
+        self.region
+    }
+
+    /// The snapshot id
+    #[must_use]
+    pub fn id(&self) -> AwsSnapshotId {
+        self.id
+    }
+}
+
+impl TryFrom<&str> for SnapshotRef {
+    type Error = crate::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let error = || SnapshotRefError(s.into());
+        if s.starts_with("arn:") {
+            let arn = Arn::try_from(s)?;
+            return match (
+                arn.service(),
+                arn.region(),
+                arn.account(),
+                arn.resource().strip_prefix("snapshot/"),
+            ) {
+                ("ec2", Some(region), Some(_), Some(id)) => Ok(Self::new(region, id.parse()?)),
+                _ => Err(error().into()),
+            };
+        }
+        let (region, id) = s.split_once(':').ok_or_else(error)?;
+        Ok(Self::new(region.parse()?, id.parse()?))
+    }
+}
+
+impl TryFrom<String> for SnapshotRef {
+    type Error = crate::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl TryFrom<&String> for SnapshotRef {
+    type Error = crate::Error;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl FromStr for SnapshotRef {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for SnapshotRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.region, self.id)
+    }
+}
+
+impl From<SnapshotRef> for String {
+    fn from(value: SnapshotRef) -> Self {
+        value.to_string()
+    }
+}
+
+/// A region together with an account, formatted as `{region}:{account}`,
+/// e.g. `us-east-1:123456789012`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RegionAccount {
+    region: AwsRegionId,
+    account: AwsAccountId,
+}
+
+impl RegionAccount {
+    /// Creates a region and account pair
+    #[must_use]
+    pub fn new(region: AwsRegionId, account: AwsAccountId) -> Self {
+        Self { region, account }
+    }
+
+    /// The region
+    #[must_use]
+    pub fn region(&self) -> AwsRegionId {
+        self.region
+    }
+
+    /// The account
+    #[must_use]
+    pub fn account(&self) -> AwsAccountId {
+        self.account
+    }
+}
+
+impl TryFrom<&str> for RegionAccount {
+    type Error = crate::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s.split(':').collect::<Vec<_>>()[..] {
+            [region, account] => Ok(Self::new(region.parse()?, account.parse()?)),
+            _ => Err(RegionAccountError(s.into()).into()),
+        }
+    }
+}
+
+impl TryFrom<String> for RegionAccount {
+    type Error = crate::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl TryFrom<&String> for RegionAccount {
+    type Error = crate::Error;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl FromStr for RegionAccount {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for RegionAccount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.region, self.account)
+    }
+}
+
+impl From<RegionAccount> for String {
+    fn from(value: RegionAccount) -> Self {
+        value.to_string()
+    }
+}
+
+/// A resource shared from another account, formatted as `{account}/{id}`,
+/// e.g. `123456789012/ami-1234567890abcdef0` in AMI sharing automation
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SharedResourceRef<T> {
+    account: AwsAccountId,
+    id: T,
+}
+
+impl<T> SharedResourceRef<T> {
+    /// Creates a shared resource reference
+    #[must_use]
+    pub fn new(account: AwsAccountId, id: T) -> Self {
+        Self { account, id }
+    }
+
+    /// The owner account
+    #[must_use]
+    pub fn account(&self) -> AwsAccountId {
+        self.account
+    }
+
+    /// The resource id
+    #[must_use]
+    pub fn id(&self) -> &T {
+        &self.id
+    }
+}
+
+impl<T> TryFrom<&str> for SharedResourceRef<T>
+where
+    T: FromStr<Err = crate::Error>,
+{
+    type Error = crate::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s.split('/').collect::<Vec<_>>()[..] {
+            [account, id] => Ok(Self::new(account.parse()?, id.parse()?)),
+            _ => Err(SharedRefError(s.into()).into()),
+        }
+    }
+}
+
+impl<T> TryFrom<String> for SharedResourceRef<T>
+where
+    T: FromStr<Err = crate::Error>,
+{
+    type Error = crate::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl<T> TryFrom<&String> for SharedResourceRef<T>
+where
+    T: FromStr<Err = crate::Error>,
+{
+    type Error = crate::Error;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl<T> FromStr for SharedResourceRef<T>
+where
+    T: FromStr<Err = crate::Error>,
+{
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for SharedResourceRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.account, self.id)
+    }
+}
+
+impl<T: fmt::Display> From<SharedResourceRef<T>> for String {
+    fn from(value: SharedResourceRef<T>) -> Self {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AwsAmiId, AwsInstanceId, Error};
+
+    #[test]
+    fn test_parse() {
+        let scoped: RegionScopedId<AwsInstanceId> =
+            "us-east-1/i-1234567890abcdef0".parse().unwrap();
+        assert_eq!(scoped.region(), AwsRegionId::UsEast1);
+        assert_eq!(scoped.id().to_string(), "i-1234567890abcdef0");
+        assert_eq!(scoped.to_string(), "us-east-1/i-1234567890abcdef0");
+    }
+
+    #[test]
+    fn test_bad_region() {
+        let result = RegionScopedId::<AwsInstanceId>::try_from("us-east-9/i-1234abcd");
+        assert!(matches!(result, Err(Error::Region(_))));
+    }
+
+    #[test]
+    fn test_bad_id() {
+        let result = RegionScopedId::<AwsInstanceId>::try_from("us-east-1/vpc-1234abcd");
+        assert!(matches!(result, Err(Error::General(_))));
+    }
+
+    #[test]
+    fn test_missing_separator() {
+        let result = RegionScopedId::<AwsInstanceId>::try_from("us-east-1:i-1234abcd");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "missing \"/\" between the region and the id in \"us-east-1:i-1234abcd\""
+        );
+    }
+
+    #[test]
+    fn test_snapshot_ref_arn() {
+        let r: SnapshotRef = "arn:aws:ec2:eu-west-1:123456789012:snapshot/snap-1234abcd"
+            .parse()
+            .unwrap();
+        assert_eq!(r.region(), AwsRegionId::EuWest1);
+        assert_eq!(r.id().to_string(), "snap-1234abcd");
+        assert_eq!(r.to_string(), "eu-west-1:snap-1234abcd");
+    }
+
+    #[test]
+    fn test_snapshot_ref_arn_consistent_with_arn() {
+        for input in [
+            "arn:aws:ec2:eu-west-1:123456789012:snapshot/snap-1234abcd",
+            "arn:aws-us-gov:ec2:us-gov-west-1:123456789012:snapshot/snap-1234abcd",
+            "arn:aws:ec2:eu-west-1:123456789012",
+            "arn:aws-eu:ec2:eu-west-1:123456789012:snapshot/snap-1234abcd",
+            "arn::ec2:eu-west-1:123456789012:snapshot/snap-1234abcd",
+            "arn:aws:ec2:eu-west-1:12345:snapshot/snap-1234abcd",
+        ] {
+            let arn = Arn::try_from(input);
+            let snapshot = SnapshotRef::try_from(input);
+            assert_eq!(arn.is_ok(), snapshot.is_ok(), "{input}");
+            if let Err(e) = arn {
+                assert_eq!(e.to_string(), snapshot.unwrap_err().to_string(), "{input}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_snapshot_ref_shorthand() {
+        let r: SnapshotRef = "us-east-1:snap-1234567890abcdef0".parse().unwrap();
+        assert_eq!(r.region(), AwsRegionId::UsEast1);
+        assert_eq!(r.id().to_string(), "snap-1234567890abcdef0");
+    }
+
+    #[test]
+    fn test_snapshot_ref_invalid() {
+        assert!(matches!(
+            SnapshotRef::try_from("arn:aws:ec2:eu-west-1:123456789012:volume/vol-1234abcd"),
+            Err(Error::SnapshotRef(_))
+        ));
+        assert!(matches!(
+            SnapshotRef::try_from("arn:aws:s3:::bucket"),
+            Err(Error::SnapshotRef(_))
+        ));
+        assert!(matches!(
+            SnapshotRef::try_from("arn:aws:ec2:eu-west-1::snapshot/snap-1234abcd"),
+            Err(Error::SnapshotRef(_))
+        ));
+        assert!(matches!(
+            SnapshotRef::try_from("arn:foo:ec2:eu-west-1:123456789012:snapshot/snap-1234abcd"),
+            Err(Error::Arn(_))
+        ));
+        assert!(matches!(
+            SnapshotRef::try_from("snap-1234abcd"),
+            Err(Error::SnapshotRef(_))
+        ));
+        assert!(matches!(
+            SnapshotRef::try_from("eu-west-9:snap-1234abcd"),
+            Err(Error::Region(_))
+        ));
+        assert!(matches!(
+            SnapshotRef::try_from("eu-west-1:vol-1234abcd"),
+            Err(Error::General(_))
+        ));
+    }
+
+    #[test]
+    fn test_region_account() {
+        let pair: RegionAccount = "us-east-1:123456789012".parse().unwrap();
+        assert_eq!(pair.region(), AwsRegionId::UsEast1);
+        assert_eq!(pair.account().to_string(), "123456789012");
+        assert_eq!(pair.to_string(), "us-east-1:123456789012");
+    }
+
+    #[test]
+    fn test_region_account_invalid() {
+        for input in ["us-east-1", "us-east-1:123456789012:extra", ""] {
+            assert!(
+                matches!(RegionAccount::try_from(input), Err(Error::RegionAccount(_))),
+                "{input}"
+            );
+        }
+        assert!(matches!(
+            RegionAccount::try_from("us-east-9:123456789012"),
+            Err(Error::Region(_))
+        ));
+        assert!(matches!(
+            RegionAccount::try_from("us-east-1:1234"),
+            Err(Error::Account(_))
+        ));
+        assert_eq!(
+            RegionAccount::try_from("us-east-1")
+                .unwrap_err()
+                .to_string(),
+            "invalid region and account \"us-east-1\", expected \"{region}:{account}\""
+        );
+    }
+
+    #[test]
+    fn test_shared_ref() {
+        let shared: SharedResourceRef<AwsAmiId> =
+            "123456789012/ami-1234567890abcdef0".parse().unwrap();
+        assert_eq!(shared.account().to_string(), "123456789012");
+        assert_eq!(shared.id().to_string(), "ami-1234567890abcdef0");
+        assert_eq!(shared.to_string(), "123456789012/ami-1234567890abcdef0");
+    }
+
+    #[test]
+    fn test_shared_ref_invalid() {
+        for input in ["ami-1234abcd", "123456789012/ami-1234abcd/x", ""] {
+            assert!(
+                matches!(
+                    SharedResourceRef::<AwsAmiId>::try_from(input),
+                    Err(Error::SharedRef(_))
+                ),
+                "{input}"
+            );
+        }
+        assert!(matches!(
+            SharedResourceRef::<AwsAmiId>::try_from("1234/ami-1234abcd"),
+            Err(Error::Account(_))
+        ));
+        assert!(matches!(
+            SharedResourceRef::<AwsAmiId>::try_from("123456789012/snap-1234abcd"),
+            Err(Error::General(_))
+        ));
+        assert_eq!(
+            SharedResourceRef::<AwsAmiId>::try_from("ami-1234abcd")
+                .unwrap_err()
+                .to_string(),
+            "invalid shared resource reference \"ami-1234abcd\", expected \"{account}/{id}\""
+        );
+    }
+}
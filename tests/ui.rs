@@ -0,0 +1,7 @@
+//! Checks that ids of different types don't convert into each other, the
+//! expected compiler errors are in the `.stderr` files next to the cases
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
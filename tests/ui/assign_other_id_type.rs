@@ -0,0 +1,6 @@
+use aws_resource_id::{AwsSnapshotId, AwsVolumeId};
+
+fn main() {
+    let snapshot: AwsSnapshotId = "snap-1234abcd".parse().unwrap();
+    let _volume: AwsVolumeId = snapshot;
+}
@@ -0,0 +1,6 @@
+use aws_resource_id::{AwsAmiId, AwsInstanceId};
+
+fn main() {
+    let instance: AwsInstanceId = "i-1234abcd".parse().unwrap();
+    let _ami = AwsAmiId::from(instance);
+}
@@ -0,0 +1,8 @@
+use aws_resource_id::{AwsAmiId, AwsInstanceId};
+
+fn takes_ami(_: AwsAmiId) {}
+
+fn main() {
+    let instance: AwsInstanceId = "i-1234abcd".parse().unwrap();
+    takes_ami(instance);
+}
@@ -0,0 +1,48 @@
+//! Checks that deserializing a region doesn't allocate, the test binary counts
+//! allocations with a global allocator, so it contains a single test
+use aws_resource_id::AwsRegionId;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn deserialize_region_without_allocating() {
+    let json = "\"eu-central-1\"";
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    let region: AwsRegionId = serde_json::from_str(json).unwrap();
+    let allocations = ALLOCATIONS.load(Ordering::SeqCst) - before;
+
+    assert_eq!(region, AwsRegionId::EuCentral1);
+    assert_eq!(allocations, 0);
+
+    // Trimming borrows the input too, only lowercasing has to copy it
+    #[cfg(feature = "serde-lenient")]
+    {
+        let json = "\" eu-central-1 \"";
+        let before = ALLOCATIONS.load(Ordering::SeqCst);
+        let region: AwsRegionId = serde_json::from_str(json).unwrap();
+        let allocations = ALLOCATIONS.load(Ordering::SeqCst) - before;
+
+        assert_eq!(region, AwsRegionId::EuCentral1);
+        assert_eq!(allocations, 0);
+    }
+}